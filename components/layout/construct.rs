@@ -1318,17 +1318,18 @@ impl<'a, ConcreteThreadSafeLayoutNode: ThreadSafeLayoutNode>
         // there.
         let mut initial_fragments = IntermediateInlineFragments::new();
         let main_fragment = self.build_fragment_for_block(node);
+        let ordinal_value = node.get_ordinal_value();
         let flow = match node.style(self.style_context()).get_list().list_style_position {
             ListStylePosition::Outside => {
                 Arc::new(ListItemFlow::from_fragments_and_flotation(
-                    main_fragment, marker_fragments, flotation))
+                    main_fragment, marker_fragments, flotation, ordinal_value))
             }
             ListStylePosition::Inside => {
                 for marker_fragment in marker_fragments {
                     initial_fragments.fragments.push_back(marker_fragment)
                 }
                 Arc::new(ListItemFlow::from_fragments_and_flotation(
-                    main_fragment, vec![], flotation))
+                    main_fragment, vec![], flotation, ordinal_value))
             }
         };
 
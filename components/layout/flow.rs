@@ -39,6 +39,7 @@ use fragment::{CoordinateSystem, Fragment, FragmentBorderBoxIterator, Overflow};
 use gfx_traits::StackingContextId;
 use gfx_traits::print_tree::PrintTree;
 use inline::InlineFlow;
+use list_item::ListItemFlow;
 use model::{CollapsibleMargins, IntrinsicISizes, MarginCollapseInfo};
 use multicol::MulticolFlow;
 use parallel::FlowParallelInfo;
@@ -223,6 +224,11 @@ pub trait Flow: HasBaseFlow + fmt::Debug + Sync + Send + 'static {
         panic!("called as_table_cell() on a non-tablecell flow")
     }
 
+    /// If this is a list item flow, returns the underlying object. Fails otherwise.
+    fn as_list_item(&self) -> &ListItemFlow {
+        panic!("called as_list_item() on a non-listitem flow")
+    }
+
     // Main methods
 
     /// Pass 1 of reflow: computes minimum and preferred inline-sizes.
@@ -10,7 +10,7 @@
 
 use context::{LayoutContext, with_thread_local_font_context};
 use display_list::items::OpaqueNode;
-use flow::{Flow, FlowFlags, GetBaseFlow, ImmutableFlowUtils};
+use flow::{Flow, FlowClass, FlowFlags, GetBaseFlow, ImmutableFlowUtils};
 use fragment::{Fragment, GeneratedContentInfo, SpecificFragmentInfo, UnscannedTextFragmentInfo};
 use script_layout_interface::wrapper_traits::PseudoElementType;
 use smallvec::SmallVec;
@@ -94,6 +94,16 @@ static KATAKANA_IROHA: [char; 47] = [
     'ヒ', 'モ', 'セ', 'ス'
 ];
 
+// Additive styles per CSS-COUNTER-STYLES § 6.4:
+static LOWER_ROMAN: [(usize, &'static str); 13] = [
+    (1000, "m"), (900, "cm"), (500, "d"), (400, "cd"), (100, "c"), (90, "xc"), (50, "l"),
+    (40, "xl"), (10, "x"), (9, "ix"), (5, "v"), (4, "iv"), (1, "i")
+];
+static UPPER_ROMAN: [(usize, &'static str); 13] = [
+    (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"), (100, "C"), (90, "XC"), (50, "L"),
+    (40, "XL"), (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I")
+];
+
 /// The generated content resolution traversal.
 pub struct ResolveGeneratedContent<'a> {
     /// The layout context.
@@ -121,10 +131,15 @@ impl<'a> ResolveGeneratedContent<'a> {
 impl<'a> InorderFlowTraversal for ResolveGeneratedContent<'a> {
     #[inline]
     fn process(&mut self, flow: &mut Flow, level: u32) {
+        let ordinal_value = match flow.class() {
+            FlowClass::ListItem => flow.as_list_item().ordinal_value,
+            _ => None,
+        };
         let mut mutator = ResolveGeneratedContentFragmentMutator {
             traversal: self,
             level: level,
             is_block: flow.is_block_like(),
+            ordinal_value: ordinal_value,
             incremented: false,
         };
         flow.mutate_fragments(&mut |fragment| mutator.mutate_fragment(fragment))
@@ -145,6 +160,8 @@ struct ResolveGeneratedContentFragmentMutator<'a, 'b: 'a> {
     level: u32,
     /// Whether this flow is a block flow.
     is_block: bool,
+    /// The ordinal value this flow sets for itself, if it is a list item with one.
+    ordinal_value: Option<i32>,
     /// Whether we've incremented the counter yet.
     incremented: bool,
 }
@@ -275,6 +292,12 @@ impl<'a, 'b> ResolveGeneratedContentFragmentMutator<'a, 'b> {
         }
         self.traversal.list_item.truncate_to_level(self.level);
 
+        // A list item has already been counted above, so this overwrites its own ordinal value,
+        // which may be negative, and subsequent items count on from it.
+        if let Some(ordinal_value) = self.ordinal_value {
+            self.traversal.list_item.reset(self.level, ordinal_value);
+        }
+
         for pair in &*fragment.style().get_counters().counter_reset {
             let counter_name = &*pair.name.0;
             if let Some(ref mut counter) = self.traversal.counters.get_mut(counter_name) {
@@ -530,6 +553,12 @@ fn push_representation(value: i32, list_style_type: ListStyleType, accumulator:
         ListStyleType::KatakanaIroha => {
             push_alphabetic_representation(value, &KATAKANA_IROHA, accumulator)
         }
+        ListStyleType::LowerRoman => {
+            push_roman_representation(value, &LOWER_ROMAN, accumulator)
+        }
+        ListStyleType::UpperRoman => {
+            push_roman_representation(value, &UPPER_ROMAN, accumulator)
+        }
     }
 }
 
@@ -588,6 +617,25 @@ fn push_numeric_representation(value: i32, system: &[char], accumulator: &mut St
     accumulator.extend(string.iter().cloned().rev())
 }
 
+/// Pushes the string that represents the value rendered using the given roman numeral *additive
+/// system* onto the accumulator per CSS-COUNTER-STYLES § 3.1.7.
+///
+/// Roman numerals only have a range of 1 to 3999, and values outside of it fall back to decimal
+/// per CSS-COUNTER-STYLES § 6.4.
+fn push_roman_representation(value: i32, system: &[(usize, &'static str)], accumulator: &mut String) {
+    if value < 1 || value > 3999 {
+        return push_numeric_representation(value, &DECIMAL, accumulator)
+    }
+
+    let mut value = value as usize;
+    for &(weight, symbol) in system {
+        while value >= weight {
+            accumulator.push_str(symbol);
+            value -= weight;
+        }
+    }
+}
+
 /// If the system uses a negative sign, handle negative values per CSS-COUNTER-STYLES § 2.
 ///
 /// Returns the absolute value of the counter.
@@ -14,7 +14,7 @@ use display_list::{DisplayListBuildState, ListItemFlowDisplayListBuilding};
 use display_list::StackingContextCollectionState;
 use euclid::Point2D;
 use floats::FloatKind;
-use flow::{Flow, FlowClass, OpaqueFlow};
+use flow::{Flow, FlowClass, FlowFlags, OpaqueFlow};
 use fragment::{CoordinateSystem, Fragment, FragmentBorderBoxIterator, GeneratedContentInfo};
 use fragment::Overflow;
 use generated_content;
@@ -37,18 +37,27 @@ pub struct ListItemFlow {
     /// The marker, if outside. (Markers that are inside are instead just fragments on the interior
     /// `InlineFlow`.)
     pub marker_fragments: Vec<Fragment>,
+    /// The ordinal value this list item sets for itself, if any, like the `value` of an `li`
+    /// element. The following list items count on from it.
+    pub ordinal_value: Option<i32>,
 }
 
 impl ListItemFlow {
     pub fn from_fragments_and_flotation(main_fragment: Fragment,
                                         marker_fragments: Vec<Fragment>,
-                                        flotation: Option<FloatKind>)
+                                        flotation: Option<FloatKind>,
+                                        ordinal_value: Option<i32>)
                                         -> ListItemFlow {
         let mut this = ListItemFlow {
             block_flow: BlockFlow::from_fragment_and_float_kind(main_fragment, flotation),
             marker_fragments: marker_fragments,
+            ordinal_value: ordinal_value,
         };
 
+        if ordinal_value.is_some() {
+            this.block_flow.base.flags.insert(FlowFlags::AFFECTS_COUNTERS);
+        }
+
         if let Some(ref marker) = this.marker_fragments.first() {
             match marker.style().get_list().list_style_type {
                 ListStyleType::Disc |
@@ -128,6 +137,10 @@ impl Flow for ListItemFlow {
         &self.block_flow
     }
 
+    fn as_list_item(&self) -> &ListItemFlow {
+        self
+    }
+
     fn bubble_inline_sizes(&mut self) {
         // The marker contributes no intrinsic inline-size, so…
         self.block_flow.bubble_inline_sizes()
@@ -359,6 +359,19 @@ impl Flow for TableFlow {
             };
         }
 
+        // Percentages that add up to more than the whole table are cut down,
+        // starting from the last column, so that they can all be honored
+        // against the width of the table. This applies to author CSS widths as
+        // much as to the `width` attribute of cells.
+        //
+        // https://drafts.csswg.org/css-tables/#intrinsic-percentage-width-of-a-column
+        let mut remaining_percentage = 1.0;
+        for column_intrinsic_inline_size in &mut self.column_intrinsic_inline_sizes {
+            column_intrinsic_inline_size.percentage =
+                column_intrinsic_inline_size.percentage.min(remaining_percentage);
+            remaining_percentage -= column_intrinsic_inline_size.percentage;
+        }
+
         let total_horizontal_spacing = self.total_horizontal_spacing();
         let mut style_specified_intrinsic_inline_size =
             self.block_flow
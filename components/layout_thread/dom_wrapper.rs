@@ -74,7 +74,7 @@ use style::selector_parser::{AttrValue as SelectorAttrValue, NonTSPseudoClass, P
 use style::selector_parser::{PseudoElement, SelectorImpl, extended_filtering};
 use style::shared_lock::{SharedRwLock as StyleSharedRwLock, Locked as StyleLocked};
 use style::str::is_whitespace;
-use style::stylist::CascadeData;
+use style::stylist::{CascadeData, Stylist};
 
 pub unsafe fn drop_style_and_layout_data(data: OpaqueStyleAndLayoutData) {
     let ptr = data.ptr.as_ptr() as *mut StyleData;
@@ -564,14 +564,15 @@ impl<'le> TElement for ServoLayoutElement<'le> {
 
     fn synthesize_presentational_hints_for_legacy_attributes<V>(
         &self,
-        _visited_handling: VisitedHandlingMode,
+        stylist: &Stylist,
+        visited_handling: VisitedHandlingMode,
         hints: &mut V,
     )
     where
         V: Push<ApplicableDeclarationBlock>,
     {
         unsafe {
-            self.element.synthesize_presentational_hints_for_legacy_attributes(hints);
+            self.element.synthesize_presentational_hints_for_legacy_attributes(stylist, visited_handling, hints);
         }
     }
 
@@ -787,6 +788,14 @@ impl<'le> ::selectors::Element for ServoLayoutElement<'le> {
                     _ => true,
                 }
             },
+            NonTSPseudoClass::ServoDetailsWithoutSummary => {
+                self.element.local_name() == &local_name!("details") &&
+                self.element.namespace() == &ns!(html) &&
+                self.as_node().dom_children().all(|node| node.as_element().map_or(true, |element| {
+                    element.element.local_name() != &local_name!("summary") ||
+                    element.element.namespace() != &ns!(html)
+                }))
+            },
             NonTSPseudoClass::ServoCaseSensitiveTypeAttr(ref expected_value) => {
                 self.get_attr_enum(&ns!(), &local_name!("type"))
                     .map_or(false, |attr| attr == expected_value)
@@ -1039,6 +1048,18 @@ impl<'ln> ThreadSafeLayoutNode for ServoThreadSafeLayoutNode<'ln> {
             self.get_jsmanaged().downcast::<Element>().unwrap().get_rowspan()
         }
     }
+
+    fn get_span(&self) -> u32 {
+        unsafe {
+            self.get_jsmanaged().downcast::<Element>().unwrap().get_span()
+        }
+    }
+
+    fn get_ordinal_value(&self) -> Option<i32> {
+        unsafe {
+            self.get_jsmanaged().downcast::<Element>().and_then(|element| element.get_ordinal_value())
+        }
+    }
 }
 
 pub struct ThreadSafeLayoutNodeChildrenIterator<ConcreteNode: ThreadSafeLayoutNode> {
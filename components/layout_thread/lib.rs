@@ -97,6 +97,7 @@ use parking_lot::RwLock;
 use profile_traits::mem::{self, Report, ReportKind, ReportsChan};
 use profile_traits::time::{self, TimerMetadata, profile};
 use profile_traits::time::{TimerMetadataFrameType, TimerMetadataReflowType};
+use script::layout_exports::attribute_affects_presentational_hints;
 use script_layout_interface::message::{Msg, NewLayoutThreadInfo, NodesFromPointQueryType, Reflow};
 use script_layout_interface::message::{ReflowComplete, QueryMsg, ReflowGoal, ScriptReflow};
 use script_layout_interface::rpc::{LayoutRPC, StyleResponse, OffsetParentResponse};
@@ -137,7 +138,7 @@ use style::selector_parser::SnapshotMap;
 use style::servo::restyle_damage::ServoRestyleDamage;
 use style::shared_lock::{SharedRwLock, SharedRwLockReadGuard, StylesheetGuards};
 use style::stylesheets::{Origin, Stylesheet, DocumentStyleSheet, StylesheetInDocument, UserAgentStylesheets};
-use style::stylist::Stylist;
+use style::stylist::{PresentationalHintsProfile, Stylist};
 use style::thread_state::{self, ThreadState};
 use style::timer::Timer;
 use style::traversal::DomTraversal;
@@ -491,6 +492,16 @@ impl LayoutThread {
         let font_cache_receiver =
             ROUTER.route_ipc_receiver_to_new_mpsc_receiver(ipc_font_cache_receiver);
 
+        let mut stylist = Stylist::new(device, QuirksMode::NoQuirks);
+        stylist.set_use_ch_unit(
+            PREFS.get("layout.legacy_attributes.use_ch_unit.enabled").as_boolean().unwrap_or(false));
+        stylist.set_logical_alignment_hints(
+            PREFS.get("layout.legacy_attributes.logical_alignment.enabled").as_boolean().unwrap_or(false));
+        stylist.set_presentational_hints_profile(
+            PREFS.get("layout.legacy_attributes.profile").as_string()
+                .and_then(PresentationalHintsProfile::from_name)
+                .unwrap_or(PresentationalHintsProfile::Standard));
+        stylist.set_built_in_hint_attributes(attribute_affects_presentational_hints);
 
         LayoutThread {
             id: id,
@@ -523,7 +534,7 @@ impl LayoutThread {
             viewport_size: Size2D::new(Au(0), Au(0)),
             webrender_api: webrender_api_sender.create_api(),
             webrender_document,
-            stylist: Stylist::new(device, QuirksMode::NoQuirks),
+            stylist: stylist,
             rw_data: Arc::new(Mutex::new(
                 LayoutThreadData {
                     constellation_chan: constellation_chan,
@@ -85,7 +85,6 @@ use dom::touchevent::TouchEvent;
 use dom::touchlist::TouchList;
 use dom::treewalker::TreeWalker;
 use dom::uievent::UIEvent;
-use dom::virtualmethods::vtable_for;
 use dom::webglcontextevent::WebGLContextEvent;
 use dom::window::{ReflowReason, Window};
 use dom::windowproxy::WindowProxy;
@@ -2217,6 +2216,7 @@ pub trait LayoutDocumentHelpers {
     unsafe fn will_paint(&self);
     unsafe fn quirks_mode(&self) -> QuirksMode;
     unsafe fn style_shared_lock(&self) -> &StyleSharedRwLock;
+    unsafe fn body_for_layout(&self) -> Option<LayoutDom<HTMLBodyElement>>;
 }
 
 #[allow(unsafe_code)]
@@ -2258,6 +2258,30 @@ impl LayoutDocumentHelpers for LayoutDom<Document> {
     unsafe fn style_shared_lock(&self) -> &StyleSharedRwLock {
         (*self.unsafe_get()).style_shared_lock()
     }
+
+    // https://html.spec.whatwg.org/multipage/#the-body-element-2
+    unsafe fn body_for_layout(&self) -> Option<LayoutDom<HTMLBodyElement>> {
+        let mut root = self.upcast::<Node>().first_child_ref();
+        while let Some(node) = root {
+            if node.downcast::<HTMLHtmlElement>().is_some() {
+                break;
+            }
+            root = node.next_sibling_ref();
+        }
+
+        let mut child = root?.first_child_ref();
+        while let Some(node) = child {
+            match node.type_id_for_layout() {
+                NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLBodyElement)) |
+                NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLFrameSetElement)) => {
+                    return node.downcast::<HTMLBodyElement>();
+                },
+                _ => {},
+            }
+            child = node.next_sibling_ref();
+        }
+        None
+    }
 }
 
 // https://html.spec.whatwg.org/multipage/#is-a-registrable-domain-suffix-of-or-is-equal-to
@@ -2698,7 +2722,7 @@ impl Document {
             entry.hint.insert(RestyleHint::RESTYLE_STYLE_ATTRIBUTE);
         }
 
-        if vtable_for(el.upcast()).attribute_affects_presentational_hints(attr) {
+        if el.attribute_affects_presentational_hints(attr) {
             entry.hint.insert(RestyleHint::RESTYLE_SELF);
         }
 
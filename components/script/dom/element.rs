@@ -31,44 +31,41 @@ use dom::bindings::xmlname::XMLName::InvalidXMLName;
 use dom::characterdata::CharacterData;
 use dom::create::create_element;
 use dom::customelementregistry::{CallbackReaction, CustomElementDefinition, CustomElementReaction};
-use dom::document::{Document, LayoutDocumentHelpers};
+use dom::document::Document;
 use dom::documentfragment::DocumentFragment;
 use dom::domrect::DOMRect;
 use dom::domtokenlist::DOMTokenList;
 use dom::event::Event;
 use dom::eventtarget::EventTarget;
 use dom::htmlanchorelement::HTMLAnchorElement;
-use dom::htmlbodyelement::{HTMLBodyElement, HTMLBodyElementLayoutHelpers};
+use dom::htmlbodyelement::HTMLBodyElement;
 use dom::htmlbuttonelement::HTMLButtonElement;
-use dom::htmlcanvaselement::{HTMLCanvasElement, LayoutHTMLCanvasElementHelpers};
 use dom::htmlcollection::HTMLCollection;
+use dom::htmldetailselement::HTMLDetailsElement;
 use dom::htmlelement::HTMLElement;
 use dom::htmlfieldsetelement::HTMLFieldSetElement;
-use dom::htmlfontelement::{HTMLFontElement, HTMLFontElementLayoutHelpers};
 use dom::htmlformelement::FormControlElementHelpers;
-use dom::htmlhrelement::{HTMLHRElement, HTMLHRLayoutHelpers};
-use dom::htmliframeelement::{HTMLIFrameElement, HTMLIFrameElementLayoutMethods};
-use dom::htmlimageelement::{HTMLImageElement, LayoutHTMLImageElementHelpers};
 use dom::htmlinputelement::{HTMLInputElement, LayoutHTMLInputElementHelpers};
 use dom::htmllabelelement::HTMLLabelElement;
 use dom::htmllegendelement::HTMLLegendElement;
+use dom::htmllielement::{HTMLLIElement, HTMLLIElementLayoutHelpers};
 use dom::htmllinkelement::HTMLLinkElement;
 use dom::htmlobjectelement::HTMLObjectElement;
 use dom::htmloptgroupelement::HTMLOptGroupElement;
 use dom::htmlselectelement::HTMLSelectElement;
 use dom::htmlstyleelement::HTMLStyleElement;
 use dom::htmltablecellelement::{HTMLTableCellElement, HTMLTableCellElementLayoutHelpers};
-use dom::htmltableelement::{HTMLTableElement, HTMLTableElementLayoutHelpers};
-use dom::htmltablerowelement::{HTMLTableRowElement, HTMLTableRowElementLayoutHelpers};
-use dom::htmltablesectionelement::{HTMLTableSectionElement, HTMLTableSectionElementLayoutHelpers};
+use dom::htmltablecolelement::{HTMLTableColElement, HTMLTableColElementLayoutHelpers};
+use dom::htmltableelement::HTMLTableElement;
 use dom::htmltemplateelement::HTMLTemplateElement;
-use dom::htmltextareaelement::{HTMLTextAreaElement, LayoutHTMLTextAreaElementHelpers};
+use dom::htmltextareaelement::HTMLTextAreaElement;
 use dom::mutationobserver::{Mutation, MutationObserver};
 use dom::namednodemap::NamedNodeMap;
 use dom::node::{ChildrenMutation, LayoutNodeHelpers, Node};
 use dom::node::{NodeDamage, NodeFlags, UnbindContext};
 use dom::node::{document_from_node, window_from_node};
 use dom::nodelist::NodeList;
+use dom::presentational_hints;
 use dom::promise::Promise;
 use dom::servoparser::ServoParser;
 use dom::text::Text;
@@ -90,7 +87,7 @@ use script_layout_interface::message::ReflowGoal;
 use script_thread::ScriptThread;
 use selectors::Element as SelectorsElement;
 use selectors::attr::{AttrSelectorOperation, NamespaceConstraint, CaseSensitivity};
-use selectors::matching::{ElementSelectorFlags, MatchingContext};
+use selectors::matching::{ElementSelectorFlags, MatchingContext, VisitedHandlingMode};
 use selectors::sink::Push;
 use servo_arc::Arc;
 use servo_atoms::Atom;
@@ -103,22 +100,18 @@ use std::rc::Rc;
 use std::str::FromStr;
 use style::CaseSensitivityExt;
 use style::applicable_declarations::ApplicableDeclarationBlock;
-use style::attr::{AttrValue, LengthOrPercentageOrAuto};
+use style::attr::AttrValue;
 use style::context::QuirksMode;
 use style::dom_apis;
 use style::element_state::ElementState;
 use style::invalidation::element::restyle_hints::RestyleHint;
-use style::properties::{ComputedValues, Importance, PropertyDeclaration};
-use style::properties::{PropertyDeclarationBlock, parse_style_attribute};
-use style::properties::longhands::{self, background_image, border_spacing, font_family, font_size};
+use style::properties::{ComputedValues, PropertyDeclarationBlock, parse_style_attribute};
 use style::properties::longhands::{overflow_x, overflow_y};
-use style::rule_tree::CascadeLevel;
 use style::selector_parser::{NonTSPseudoClass, PseudoElement, RestyleDamage, SelectorImpl, SelectorParser};
 use style::selector_parser::extended_filtering;
-use style::shared_lock::{SharedRwLock, Locked};
+use style::shared_lock::Locked;
+use style::stylist::Stylist;
 use style::thread_state;
-use style::values::{CSSFloat, Either};
-use style::values::{specified, computed};
 use stylesheet_loader::StylesheetOwner;
 use task::TaskOnce;
 use xml5ever::serialize as xmlSerialize;
@@ -467,13 +460,21 @@ pub trait LayoutElementHelpers {
     unsafe fn get_classes_for_layout(&self) -> Option<&'static [Atom]>;
 
     #[allow(unsafe_code)]
-    unsafe fn synthesize_presentational_hints_for_legacy_attributes<V>(&self, &mut V)
+    unsafe fn synthesize_presentational_hints_for_legacy_attributes<V>(&self, &Stylist, VisitedHandlingMode, &mut V)
         where V: Push<ApplicableDeclarationBlock>;
     #[allow(unsafe_code)]
+    unsafe fn serialize_presentational_hints(&self, &Stylist) -> String;
+    #[allow(unsafe_code)]
+    unsafe fn presentational_hints_to_css_string(&self, &Stylist) -> String;
+    #[allow(unsafe_code)]
     unsafe fn get_colspan(self) -> u32;
     #[allow(unsafe_code)]
     unsafe fn get_rowspan(self) -> u32;
     #[allow(unsafe_code)]
+    unsafe fn get_span(self) -> u32;
+    #[allow(unsafe_code)]
+    unsafe fn get_ordinal_value(self) -> Option<i32>;
+    #[allow(unsafe_code)]
     unsafe fn is_html_element(&self) -> bool;
     fn id_attribute(&self) -> *const Option<Atom>;
     fn style_attribute(&self) -> *const Option<Arc<Locked<PropertyDeclarationBlock>>>;
@@ -504,285 +505,26 @@ impl LayoutElementHelpers for LayoutDom<Element> {
     }
 
     #[allow(unsafe_code)]
-    unsafe fn synthesize_presentational_hints_for_legacy_attributes<V>(&self, hints: &mut V)
+    unsafe fn synthesize_presentational_hints_for_legacy_attributes<V>(&self,
+                                                                       stylist: &Stylist,
+                                                                       visited_handling: VisitedHandlingMode,
+                                                                       hints: &mut V)
         where V: Push<ApplicableDeclarationBlock>
     {
-        // FIXME(emilio): Just a single PDB should be enough.
-        #[inline]
-        fn from_declaration(shared_lock: &SharedRwLock, declaration: PropertyDeclaration)
-                            -> ApplicableDeclarationBlock {
-            ApplicableDeclarationBlock::from_declarations(
-                Arc::new(shared_lock.wrap(PropertyDeclarationBlock::with_one(
-                    declaration, Importance::Normal
-                ))),
-                CascadeLevel::PresHints)
-        }
-
-        let document = self.upcast::<Node>().owner_doc_for_layout();
-        let shared_lock = document.style_shared_lock();
-
-        let bgcolor = if let Some(this) = self.downcast::<HTMLBodyElement>() {
-            this.get_background_color()
-        } else if let Some(this) = self.downcast::<HTMLTableElement>() {
-            this.get_background_color()
-        } else if let Some(this) = self.downcast::<HTMLTableCellElement>() {
-            this.get_background_color()
-        } else if let Some(this) = self.downcast::<HTMLTableRowElement>() {
-            this.get_background_color()
-        } else if let Some(this) = self.downcast::<HTMLTableSectionElement>() {
-            this.get_background_color()
-        } else {
-            None
-        };
-
-        if let Some(color) = bgcolor {
-            hints.push(from_declaration(
-                shared_lock,
-                PropertyDeclaration::BackgroundColor(color.into())
-            ));
-        }
-
-        let background = if let Some(this) = self.downcast::<HTMLBodyElement>() {
-            this.get_background()
-        } else {
-            None
-        };
-
-        if let Some(url) = background {
-            hints.push(from_declaration(
-                shared_lock,
-                PropertyDeclaration::BackgroundImage(
-                    background_image::SpecifiedValue(vec![
-                        Either::Second(specified::Image::for_cascade(url.into()))
-                    ]))));
-        }
-
-        let color = if let Some(this) = self.downcast::<HTMLFontElement>() {
-            this.get_color()
-        } else if let Some(this) = self.downcast::<HTMLBodyElement>() {
-            // https://html.spec.whatwg.org/multipage/#the-page:the-body-element-20
-            this.get_color()
-        } else if let Some(this) = self.downcast::<HTMLHRElement>() {
-            // https://html.spec.whatwg.org/multipage/#the-hr-element-2:presentational-hints-5
-            this.get_color()
-        } else {
-            None
-        };
-
-        if let Some(color) = color {
-            hints.push(from_declaration(
-                shared_lock,
-                PropertyDeclaration::Color(
-                    longhands::color::SpecifiedValue(color.into())
-                )
-            ));
-        }
-
-        let font_family = if let Some(this) = self.downcast::<HTMLFontElement>() {
-            this.get_face()
-        } else {
-            None
-        };
-
-        if let Some(font_family) = font_family {
-            hints.push(from_declaration(
-                shared_lock,
-                PropertyDeclaration::FontFamily(
-                    font_family::SpecifiedValue::Values(
-                        computed::font::FontFamilyList::new(Box::new([
-                            computed::font::SingleFontFamily::from_atom(
-                                font_family)]))))));
-        }
-
-        let font_size = self.downcast::<HTMLFontElement>().and_then(|this| this.get_size());
-
-        if let Some(font_size) = font_size {
-            hints.push(from_declaration(
-                shared_lock,
-                PropertyDeclaration::FontSize(
-                    font_size::SpecifiedValue::from_html_size(font_size as u8)
-                )
-            ))
-        }
-
-        let cellspacing = if let Some(this) = self.downcast::<HTMLTableElement>() {
-            this.get_cellspacing()
-        } else {
-            None
-        };
-
-        if let Some(cellspacing) = cellspacing {
-            let width_value = specified::Length::from_px(cellspacing as f32);
-            hints.push(from_declaration(
-                shared_lock,
-                PropertyDeclaration::BorderSpacing(
-                    Box::new(border_spacing::SpecifiedValue::new(
-                        width_value.clone().into(),
-                        width_value.into()
-                    ))
-                )
-            ));
-        }
-
-
-        let size = if let Some(this) = self.downcast::<HTMLInputElement>() {
-            // FIXME(pcwalton): More use of atoms, please!
-            match (*self.unsafe_get()).get_attr_val_for_layout(&ns!(), &local_name!("type")) {
-                // Not text entry widget
-                Some("hidden") | Some("date") | Some("month") | Some("week") |
-                Some("time") | Some("datetime-local") | Some("number") | Some("range") |
-                Some("color") | Some("checkbox") | Some("radio") | Some("file") |
-                Some("submit") | Some("image") | Some("reset") | Some("button") => {
-                    None
-                },
-                // Others
-                _ => {
-                    match this.size_for_layout() {
-                        0 => None,
-                        s => Some(s as i32),
-                    }
-                },
-            }
-        } else {
-            None
-        };
-
-        if let Some(size) = size {
-            let value = specified::NoCalcLength::ServoCharacterWidth(specified::CharacterWidth(size));
-            hints.push(from_declaration(
-                shared_lock,
-                PropertyDeclaration::Width(
-                    specified::LengthOrPercentageOrAuto::Length(value))));
-        }
-
-        let width = if let Some(this) = self.downcast::<HTMLIFrameElement>() {
-            this.get_width()
-        } else if let Some(this) = self.downcast::<HTMLImageElement>() {
-            this.get_width()
-        } else if let Some(this) = self.downcast::<HTMLTableElement>() {
-            this.get_width()
-        } else if let Some(this) = self.downcast::<HTMLTableCellElement>() {
-            this.get_width()
-        } else if let Some(this) = self.downcast::<HTMLHRElement>() {
-            // https://html.spec.whatwg.org/multipage/#the-hr-element-2:attr-hr-width
-            this.get_width()
-        } else if let Some(this) = self.downcast::<HTMLCanvasElement>() {
-            this.get_width()
-        } else {
-            LengthOrPercentageOrAuto::Auto
-        };
-
-        // FIXME(emilio): Use from_computed value here and below.
-        match width {
-            LengthOrPercentageOrAuto::Auto => {}
-            LengthOrPercentageOrAuto::Percentage(percentage) => {
-                let width_value =
-                    specified::LengthOrPercentageOrAuto::Percentage(computed::Percentage(percentage));
-                hints.push(from_declaration(
-                    shared_lock,
-                    PropertyDeclaration::Width(width_value)));
-            }
-            LengthOrPercentageOrAuto::Length(length) => {
-                let width_value = specified::LengthOrPercentageOrAuto::Length(
-                    specified::NoCalcLength::Absolute(specified::AbsoluteLength::Px(length.to_f32_px())));
-                hints.push(from_declaration(
-                    shared_lock,
-                    PropertyDeclaration::Width(width_value)));
-            }
-        }
-
-
-        let height = if let Some(this) = self.downcast::<HTMLIFrameElement>() {
-            this.get_height()
-        } else if let Some(this) = self.downcast::<HTMLImageElement>() {
-            this.get_height()
-        } else if let Some(this) = self.downcast::<HTMLCanvasElement>() {
-            this.get_height()
-        } else {
-            LengthOrPercentageOrAuto::Auto
-        };
-
-        match height {
-            LengthOrPercentageOrAuto::Auto => {}
-            LengthOrPercentageOrAuto::Percentage(percentage) => {
-                let height_value =
-                    specified::LengthOrPercentageOrAuto::Percentage(computed::Percentage(percentage));
-                hints.push(from_declaration(
-                    shared_lock,
-                    PropertyDeclaration::Height(height_value)));
-            }
-            LengthOrPercentageOrAuto::Length(length) => {
-                let height_value = specified::LengthOrPercentageOrAuto::Length(
-                    specified::NoCalcLength::Absolute(specified::AbsoluteLength::Px(length.to_f32_px())));
-                hints.push(from_declaration(
-                    shared_lock,
-                    PropertyDeclaration::Height(height_value)));
-            }
-        }
-
-
-        let cols = if let Some(this) = self.downcast::<HTMLTextAreaElement>() {
-            match this.get_cols() {
-                0 => None,
-                c => Some(c as i32),
-            }
-        } else {
-            None
-        };
-
-        if let Some(cols) = cols {
-            // TODO(mttr) ServoCharacterWidth uses the size math for <input type="text">, but
-            // the math for <textarea> is a little different since we need to take
-            // scrollbar size into consideration (but we don't have a scrollbar yet!)
-            //
-            // https://html.spec.whatwg.org/multipage/#textarea-effective-width
-            let value = specified::NoCalcLength::ServoCharacterWidth(specified::CharacterWidth(cols));
-            hints.push(from_declaration(
-                shared_lock,
-                PropertyDeclaration::Width(specified::LengthOrPercentageOrAuto::Length(value))));
-        }
-
-        let rows = if let Some(this) = self.downcast::<HTMLTextAreaElement>() {
-            match this.get_rows() {
-                0 => None,
-                r => Some(r as i32),
-            }
-        } else {
-            None
-        };
-
-        if let Some(rows) = rows {
-            // TODO(mttr) This should take scrollbar size into consideration.
-            //
-            // https://html.spec.whatwg.org/multipage/#textarea-effective-height
-            let value = specified::NoCalcLength::FontRelative(specified::FontRelativeLength::Em(rows as CSSFloat));
-            hints.push(from_declaration(
-                shared_lock,
-                PropertyDeclaration::Height(specified::LengthOrPercentageOrAuto::Length(value))));
-        }
-
+        presentational_hints::synthesize_presentational_hints_for_legacy_attributes(self,
+                                                                                   stylist,
+                                                                                   visited_handling,
+                                                                                   hints)
+    }
 
-        let border = if let Some(this) = self.downcast::<HTMLTableElement>() {
-            this.get_border()
-        } else {
-            None
-        };
+    #[allow(unsafe_code)]
+    unsafe fn serialize_presentational_hints(&self, stylist: &Stylist) -> String {
+        presentational_hints::serialize_presentational_hints(self, stylist)
+    }
 
-        if let Some(border) = border {
-            let width_value = specified::BorderSideWidth::Length(specified::Length::from_px(border as f32));
-            hints.push(from_declaration(
-                shared_lock,
-                PropertyDeclaration::BorderTopWidth(width_value.clone())));
-            hints.push(from_declaration(
-                shared_lock,
-                PropertyDeclaration::BorderLeftWidth(width_value.clone())));
-            hints.push(from_declaration(
-                shared_lock,
-                PropertyDeclaration::BorderBottomWidth(width_value.clone())));
-            hints.push(from_declaration(
-                shared_lock,
-                PropertyDeclaration::BorderRightWidth(width_value)));
-        }
+    #[allow(unsafe_code)]
+    unsafe fn presentational_hints_to_css_string(&self, stylist: &Stylist) -> String {
+        presentational_hints::presentational_hints_to_css_string(self, stylist)
     }
 
     #[allow(unsafe_code)]
@@ -807,6 +549,24 @@ impl LayoutElementHelpers for LayoutDom<Element> {
         }
     }
 
+    #[allow(unsafe_code)]
+    unsafe fn get_span(self) -> u32 {
+        if let Some(this) = self.downcast::<HTMLTableColElement>() {
+            this.get_span().unwrap_or(1)
+        } else {
+            // Don't panic since `display` can cause this to be called on arbitrary
+            // elements.
+            1
+        }
+    }
+
+    #[allow(unsafe_code)]
+    unsafe fn get_ordinal_value(self) -> Option<i32> {
+        // Any element can be a list item, but only `li` elements have an
+        // ordinal value of their own.
+        self.downcast::<HTMLLIElement>().and_then(|this| this.get_ordinal_value())
+    }
+
     #[inline]
     #[allow(unsafe_code)]
     unsafe fn is_html_element(&self) -> bool {
@@ -921,6 +681,13 @@ impl Element {
         self.namespace == ns!(html)
     }
 
+    /// Whether `attr` may map to one of the built-in presentational hints of
+    /// this element, so that changing it needs to restyle it.
+    pub fn attribute_affects_presentational_hints(&self, attr: &Attr) -> bool {
+        self.is_html_element() && attr.namespace() == &ns!() &&
+            presentational_hints::attribute_affects_presentational_hints(self.local_name(), attr.local_name())
+    }
+
     pub fn html_element_in_html_document(&self) -> bool {
         self.is_html_element() && self.upcast::<Node>().is_in_html_doc()
     }
@@ -1181,6 +948,9 @@ impl Element {
         self.will_mutate_attr(attr);
         self.attrs.borrow_mut().push(Dom::from_ref(attr));
         if attr.namespace() == &ns!() {
+            if self.attribute_affects_presentational_hints(attr) {
+                self.upcast::<Node>().set_flag(NodeFlags::HAS_PRESENTATIONAL_HINT_ATTRIBUTES, true);
+            }
             vtable_for(self.upcast()).attribute_mutated(attr, AttributeMutation::Set(None));
         }
     }
@@ -1322,6 +1092,13 @@ impl Element {
             self.attrs.borrow_mut().remove(idx);
             attr.set_owner(None);
             if attr.namespace() == &ns!() {
+                if self.attribute_affects_presentational_hints(&attr) {
+                    let has_hint_attributes = self.attrs.borrow().iter().any(|attr| {
+                        self.attribute_affects_presentational_hints(attr)
+                    });
+                    self.upcast::<Node>().set_flag(NodeFlags::HAS_PRESENTATIONAL_HINT_ATTRIBUTES,
+                                                   has_hint_attributes);
+                }
                 vtable_for(self.upcast()).attribute_mutated(&attr, AttributeMutation::Removed);
             }
             attr
@@ -2387,16 +2164,6 @@ impl VirtualMethods for Element {
         Some(self.upcast::<Node>() as &VirtualMethods)
     }
 
-    fn attribute_affects_presentational_hints(&self, attr: &Attr) -> bool {
-        // FIXME: This should be more fine-grained, not all elements care about these.
-        if attr.local_name() == &local_name!("width") ||
-           attr.local_name() == &local_name!("height") {
-            return true;
-        }
-
-        self.super_type().unwrap().attribute_affects_presentational_hints(attr)
-    }
-
     fn attribute_mutated(&self, attr: &Attr, mutation: AttributeMutation) {
         self.super_type().unwrap().attribute_mutated(attr, mutation);
         let node = self.upcast::<Node>();
@@ -2687,6 +2454,10 @@ impl<'a> SelectorsElement for DomRoot<Element> {
                 }
             },
 
+            NonTSPseudoClass::ServoDetailsWithoutSummary => {
+                self.downcast::<HTMLDetailsElement>().map_or(false, |this| this.summaries().next().is_none())
+            },
+
             NonTSPseudoClass::ServoCaseSensitiveTypeAttr(ref expected_value) => {
                 self.get_attribute(&ns!(), &local_name!("type"))
                     .map_or(false, |attr| attr.value().eq(expected_value))
@@ -2932,6 +2703,11 @@ impl Element {
 
     /// <https://html.spec.whatwg.org/multipage/#concept-selector-active>
     pub fn set_active_state(&self, value: bool) {
+        // The `alink` color of the body applies to active links through a
+        // presentational hint, which state invalidation doesn't know about.
+        if self.active_state() != value && DomRoot::from_ref(self).is_link() && self.has_active_link_color() {
+            self.restyle(NodeDamage::OtherNodeDamage);
+        }
         self.set_state(ElementState::IN_ACTIVE_STATE, value);
 
         if let Some(parent) = self.upcast::<Node>().GetParentElement() {
@@ -2939,6 +2715,14 @@ impl Element {
         }
     }
 
+    /// Whether the body of the document of this element has an `alink`
+    /// attribute.
+    fn has_active_link_color(&self) -> bool {
+        self.upcast::<Node>().owner_doc().GetBody().map_or(false, |body| {
+            body.is::<HTMLBodyElement>() && body.upcast::<Element>().has_attribute(&local_name!("alink"))
+        })
+    }
+
     pub fn focus_state(&self) -> bool {
         self.state.get().contains(ElementState::IN_FOCUS_STATE)
     }
@@ -2969,6 +2753,11 @@ impl Element {
     }
 
     pub fn set_disabled_state(&self, value: bool) {
+        // Whether a text area is resizable is a presentational hint, which
+        // state invalidation doesn't know about.
+        if self.disabled_state() != value && self.is::<HTMLTextAreaElement>() {
+            self.restyle(NodeDamage::OtherNodeDamage);
+        }
         self.set_state(ElementState::IN_DISABLED_STATE, value)
     }
 
@@ -4,6 +4,7 @@
 
 use cssparser::RGBA;
 use dom::attr::Attr;
+use dom::bindings::codegen::Bindings::DocumentBinding::DocumentMethods;
 use dom::bindings::codegen::Bindings::HTMLBodyElementBinding::{self, HTMLBodyElementMethods};
 use dom::bindings::codegen::Bindings::WindowBinding::WindowMethods;
 use dom::bindings::inheritance::Castable;
@@ -19,7 +20,8 @@ use dom_struct::dom_struct;
 use embedder_traits::EmbedderMsg;
 use html5ever::{LocalName, Prefix};
 use servo_url::ServoUrl;
-use style::attr::AttrValue;
+use style::attr::{AttrValue, parse_unsigned_integer};
+use style::invalidation::element::restyle_hints::RestyleHint;
 use time;
 
 /// How long we should wait before performing the initial reflow after `<body>` is parsed, in
@@ -91,6 +93,11 @@ pub trait HTMLBodyElementLayoutHelpers {
     fn get_background_color(&self) -> Option<RGBA>;
     fn get_color(&self) -> Option<RGBA>;
     fn get_background(&self) -> Option<ServoUrl>;
+    fn get_scrolling_disabled(&self) -> bool;
+    fn get_link_color(&self) -> Option<RGBA>;
+    fn get_visited_link_color(&self) -> Option<RGBA>;
+    fn get_active_link_color(&self) -> Option<RGBA>;
+    fn get_margin(&self, specific: &'static str, generic: &'static str) -> Option<(&'static str, u32)>;
 }
 
 impl HTMLBodyElementLayoutHelpers for LayoutDom<HTMLBodyElement> {
@@ -123,19 +130,67 @@ impl HTMLBodyElementLayoutHelpers for LayoutDom<HTMLBodyElement> {
                 .cloned()
         }
     }
-}
 
-impl VirtualMethods for HTMLBodyElement {
-    fn super_type(&self) -> Option<&VirtualMethods> {
-        Some(self.upcast::<HTMLElement>() as &VirtualMethods)
+    #[allow(unsafe_code)]
+    fn get_link_color(&self) -> Option<RGBA> {
+        unsafe {
+            (*self.upcast::<Element>().unsafe_get())
+                .get_attr_for_layout(&ns!(), &local_name!("link"))
+                .and_then(AttrValue::as_color)
+                .cloned()
+        }
     }
 
-    fn attribute_affects_presentational_hints(&self, attr: &Attr) -> bool {
-        if attr.local_name() == &local_name!("bgcolor") {
-            return true;
+    #[allow(unsafe_code)]
+    fn get_visited_link_color(&self) -> Option<RGBA> {
+        unsafe {
+            (*self.upcast::<Element>().unsafe_get())
+                .get_attr_for_layout(&ns!(), &local_name!("vlink"))
+                .and_then(AttrValue::as_color)
+                .cloned()
         }
+    }
 
-        self.super_type().unwrap().attribute_affects_presentational_hints(attr)
+    #[allow(unsafe_code)]
+    fn get_active_link_color(&self) -> Option<RGBA> {
+        unsafe {
+            (*self.upcast::<Element>().unsafe_get())
+                .get_attr_for_layout(&ns!(), &local_name!("alink"))
+                .and_then(AttrValue::as_color)
+                .cloned()
+        }
+    }
+
+    /// The margin, in pixels, set by the legacy attribute `specific` (like
+    /// `topmargin`), or else by `generic` (like `marginheight`), together with
+    /// the name of the attribute it comes from.
+    ///
+    /// https://html.spec.whatwg.org/multipage/#the-page:attr-body-marginheight
+    #[allow(unsafe_code)]
+    fn get_margin(&self, specific: &'static str, generic: &'static str) -> Option<(&'static str, u32)> {
+        let margin = |name: &'static str| unsafe {
+            (*self.upcast::<Element>().unsafe_get())
+                .get_attr_val_for_layout(&ns!(), &LocalName::from(name))
+                .and_then(|value| parse_unsigned_integer(value.chars()).ok())
+                .map(|margin| (name, margin))
+        };
+        margin(specific).or_else(|| margin(generic))
+    }
+
+    /// Whether the legacy `scroll` attribute is set to `no`.
+    #[allow(unsafe_code)]
+    fn get_scrolling_disabled(&self) -> bool {
+        unsafe {
+            (*self.upcast::<Element>().unsafe_get())
+                .get_attr_val_for_layout(&ns!(), &LocalName::from("scroll"))
+                .map_or(false, |value| value.trim().eq_ignore_ascii_case("no"))
+        }
+    }
+}
+
+impl VirtualMethods for HTMLBodyElement {
+    fn super_type(&self) -> Option<&VirtualMethods> {
+        Some(self.upcast::<HTMLElement>() as &VirtualMethods)
     }
 
     fn bind_to_tree(&self, tree_in_doc: bool) {
@@ -159,7 +214,10 @@ impl VirtualMethods for HTMLBodyElement {
     fn parse_plain_attribute(&self, name: &LocalName, value: DOMString) -> AttrValue {
         match *name {
             local_name!("bgcolor") |
-            local_name!("text") => AttrValue::from_legacy_color(value.into()),
+            local_name!("text") |
+            local_name!("link") |
+            local_name!("vlink") |
+            local_name!("alink") => AttrValue::from_legacy_color(value.into()),
             local_name!("background") => {
                 AttrValue::from_resolved_url(
                     &document_from_node(self).base_url(),
@@ -171,6 +229,17 @@ impl VirtualMethods for HTMLBodyElement {
     }
 
     fn attribute_mutated(&self, attr: &Attr, mutation: AttributeMutation) {
+        if attr.local_name() == &local_name!("link") ||
+           attr.local_name() == &local_name!("vlink") ||
+           attr.local_name() == &local_name!("alink") {
+            // The link colors apply to the links of the whole document, so
+            // restyle everything.
+            let document = document_from_node(self);
+            if let Some(root) = document.GetDocumentElement() {
+                document.ensure_pending_restyle(&root).hint.insert(RestyleHint::restyle_subtree());
+            }
+        }
+
         let do_super_mutate = match (attr.local_name(), mutation) {
             (name, AttributeMutation::Set(_)) if name.starts_with("on") => {
                 let window = window_from_node(self);
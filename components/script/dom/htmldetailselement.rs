@@ -9,10 +9,10 @@ use dom::bindings::inheritance::Castable;
 use dom::bindings::refcounted::Trusted;
 use dom::bindings::root::DomRoot;
 use dom::document::Document;
-use dom::element::AttributeMutation;
+use dom::element::{AttributeMutation, Element};
 use dom::eventtarget::EventTarget;
 use dom::htmlelement::HTMLElement;
-use dom::node::{Node, window_from_node};
+use dom::node::{ChildrenMutation, Node, NodeDamage, window_from_node};
 use dom::virtualmethods::VirtualMethods;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
@@ -36,6 +36,14 @@ impl HTMLDetailsElement {
         }
     }
 
+    /// The `summary` children of this element, of which the first one is its
+    /// summary.
+    pub fn summaries(&self) -> impl Iterator<Item=DomRoot<Element>> {
+        self.upcast::<Node>().children()
+            .filter_map(DomRoot::downcast::<Element>)
+            .filter(|element| element.is_html_element() && element.local_name() == &local_name!("summary"))
+    }
+
     #[allow(unrooted_must_root)]
     pub fn new(local_name: LocalName,
                prefix: Option<Prefix>,
@@ -63,6 +71,13 @@ impl VirtualMethods for HTMLDetailsElement {
         self.super_type().unwrap().attribute_mutated(attr, mutation);
 
         if attr.local_name() == &local_name!("open") {
+            // The disclosure marker depends on the open state, and is drawn
+            // by the summary, or by the details element without one.
+            self.upcast::<Element>().restyle(NodeDamage::OtherNodeDamage);
+            if let Some(summary) = self.summaries().next() {
+                summary.restyle(NodeDamage::OtherNodeDamage);
+            }
+
             let counter = self.toggle_counter.get() + 1;
             self.toggle_counter.set(counter);
 
@@ -80,4 +95,22 @@ impl VirtualMethods for HTMLDetailsElement {
             );
         }
     }
+
+    fn children_changed(&self, mutation: &ChildrenMutation) {
+        if let Some(ref s) = self.super_type() {
+            s.children_changed(mutation);
+        }
+
+        if let ChildrenMutation::ChangeText = *mutation {
+            return;
+        }
+
+        // Inserting or removing a summary changes which one is first, and so
+        // draws the disclosure marker, and whether the details element has to
+        // draw it itself.
+        self.upcast::<Element>().restyle(NodeDamage::OtherNodeDamage);
+        for summary in self.summaries() {
+            summary.restyle(NodeDamage::OtherNodeDamage);
+        }
+    }
 }
@@ -692,6 +692,16 @@ impl VirtualMethods for HTMLElement {
         match name {
             &local_name!("itemprop") => AttrValue::from_serialized_tokenlist(value.into()),
             &local_name!("itemtype") => AttrValue::from_serialized_tokenlist(value.into()),
+            // `marquee` elements don't have their own DOM type, but map these
+            // to presentational hints like the elements that do.
+            &local_name!("width") | &local_name!("height") | &local_name!("hspace") | &local_name!("vspace")
+                if self.upcast::<Element>().local_name() == &local_name!("marquee") =>
+            {
+                AttrValue::from_dimension(value.into())
+            },
+            &local_name!("bgcolor") if self.upcast::<Element>().local_name() == &local_name!("marquee") => {
+                AttrValue::from_legacy_color(value.into())
+            },
             _ => {
                 self.super_type().unwrap().parse_plain_attribute(
                     name,
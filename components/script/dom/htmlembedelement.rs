@@ -3,12 +3,16 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use dom::bindings::codegen::Bindings::HTMLEmbedElementBinding;
+use dom::bindings::inheritance::Castable;
 use dom::bindings::root::DomRoot;
+use dom::bindings::str::DOMString;
 use dom::document::Document;
 use dom::htmlelement::HTMLElement;
 use dom::node::Node;
+use dom::virtualmethods::VirtualMethods;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
+use style::attr::AttrValue;
 
 #[dom_struct]
 pub struct HTMLEmbedElement {
@@ -31,3 +35,16 @@ impl HTMLEmbedElement {
                            HTMLEmbedElementBinding::Wrap)
     }
 }
+
+impl VirtualMethods for HTMLEmbedElement {
+    fn super_type(&self) -> Option<&VirtualMethods> {
+        Some(self.upcast::<HTMLElement>() as &VirtualMethods)
+    }
+
+    fn parse_plain_attribute(&self, name: &LocalName, value: DOMString) -> AttrValue {
+        match name {
+            &local_name!("hspace") | &local_name!("vspace") => AttrValue::from_dimension(value.into()),
+            _ => self.super_type().unwrap().parse_plain_attribute(name, value),
+        }
+    }
+}
@@ -5,12 +5,19 @@
 use dom::bindings::codegen::Bindings::HTMLFrameSetElementBinding;
 use dom::bindings::codegen::Bindings::HTMLFrameSetElementBinding::HTMLFrameSetElementMethods;
 use dom::bindings::codegen::Bindings::WindowBinding::WindowMethods;
-use dom::bindings::root::DomRoot;
+use dom::bindings::inheritance::Castable;
+use dom::attr::Attr;
+use dom::bindings::root::{DomRoot, LayoutDom};
+use dom::bindings::str::DOMString;
 use dom::document::Document;
+use dom::element::{AttributeMutation, Element, RawLayoutElementHelpers};
 use dom::htmlelement::HTMLElement;
-use dom::node::{Node, document_from_node};
+use dom::htmlframeelement::HTMLFrameElement;
+use dom::node::{ChildrenMutation, Node, NodeDamage, document_from_node};
+use dom::virtualmethods::VirtualMethods;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
+use style::attr::{AttrValue, FramesetDimension};
 
 #[dom_struct]
 pub struct HTMLFrameSetElement {
@@ -35,9 +42,79 @@ impl HTMLFrameSetElement {
                            document,
                            HTMLFrameSetElementBinding::Wrap)
     }
+
+    /// Restyles the `frame` and `frameset` children of this element, which
+    /// are laid out in the cells given by its `rows` and `cols`.
+    fn restyle_frames(&self) {
+        for child in self.upcast::<Node>().children().filter_map(DomRoot::downcast::<Element>) {
+            if child.is::<HTMLFrameElement>() || child.is::<HTMLFrameSetElement>() {
+                child.restyle(NodeDamage::OtherNodeDamage);
+            }
+        }
+    }
 }
 
 impl HTMLFrameSetElementMethods for HTMLFrameSetElement {
     // https://html.spec.whatwg.org/multipage/#windoweventhandlers
     window_event_handlers!(ForwardToWindow);
 }
+
+pub trait HTMLFrameSetElementLayoutHelpers {
+    fn get_rows(&self) -> Option<&'static [FramesetDimension]>;
+    fn get_cols(&self) -> Option<&'static [FramesetDimension]>;
+}
+
+impl HTMLFrameSetElementLayoutHelpers for LayoutDom<HTMLFrameSetElement> {
+    #[allow(unsafe_code)]
+    fn get_rows(&self) -> Option<&'static [FramesetDimension]> {
+        unsafe {
+            (*self.upcast::<Element>().unsafe_get())
+                .get_attr_for_layout(&ns!(), &local_name!("rows"))
+                .map(AttrValue::as_dimension_list)
+        }
+    }
+
+    #[allow(unsafe_code)]
+    fn get_cols(&self) -> Option<&'static [FramesetDimension]> {
+        unsafe {
+            (*self.upcast::<Element>().unsafe_get())
+                .get_attr_for_layout(&ns!(), &local_name!("cols"))
+                .map(AttrValue::as_dimension_list)
+        }
+    }
+}
+
+impl VirtualMethods for HTMLFrameSetElement {
+    fn super_type(&self) -> Option<&VirtualMethods> {
+        Some(self.upcast::<HTMLElement>() as &VirtualMethods)
+    }
+
+    fn attribute_mutated(&self, attr: &Attr, mutation: AttributeMutation) {
+        self.super_type().unwrap().attribute_mutated(attr, mutation);
+        match *attr.local_name() {
+            local_name!("rows") | local_name!("cols") => self.restyle_frames(),
+            _ => {},
+        }
+    }
+
+    fn children_changed(&self, mutation: &ChildrenMutation) {
+        if let Some(ref s) = self.super_type() {
+            s.children_changed(mutation);
+        }
+
+        if let ChildrenMutation::ChangeText = *mutation {
+            return;
+        }
+
+        // Inserting or removing a frame changes the cells the following ones
+        // are laid out in, and so which of them are rendered at all.
+        self.restyle_frames();
+    }
+
+    fn parse_plain_attribute(&self, name: &LocalName, value: DOMString) -> AttrValue {
+        match *name {
+            local_name!("rows") | local_name!("cols") => AttrValue::from_dimension_list(value.into()),
+            _ => self.super_type().unwrap().parse_plain_attribute(name, value),
+        }
+    }
+}
@@ -3,28 +3,34 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use cssparser::RGBA;
+use dom::attr::Attr;
 use dom::bindings::codegen::Bindings::HTMLHRElementBinding::{self, HTMLHRElementMethods};
 use dom::bindings::inheritance::Castable;
 use dom::bindings::root::{DomRoot, LayoutDom};
 use dom::bindings::str::DOMString;
 use dom::document::Document;
-use dom::element::{Element, RawLayoutElementHelpers};
+use dom::element::{AttributeMutation, Element, RawLayoutElementHelpers};
 use dom::htmlelement::HTMLElement;
 use dom::node::Node;
 use dom::virtualmethods::VirtualMethods;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
-use style::attr::{AttrValue, LengthOrPercentageOrAuto};
+use std::cell::Cell;
+use style::attr::{AttrValue, LengthOrPercentageOrAuto, parse_unsigned_integer};
 
 #[dom_struct]
 pub struct HTMLHRElement {
     htmlelement: HTMLElement,
+    /// The `size` attribute, parsed as a non-negative integer when it's set,
+    /// so that synthesizing presentational hints doesn't parse it again.
+    size: Cell<Option<u32>>,
 }
 
 impl HTMLHRElement {
     fn new_inherited(local_name: LocalName, prefix: Option<Prefix>, document: &Document) -> HTMLHRElement {
         HTMLHRElement {
-            htmlelement: HTMLElement::new_inherited(local_name, prefix, document)
+            htmlelement: HTMLElement::new_inherited(local_name, prefix, document),
+            size: Cell::new(None),
         }
     }
 
@@ -61,6 +67,7 @@ impl HTMLHRElementMethods for HTMLHRElement {
 pub trait HTMLHRLayoutHelpers {
     fn get_color(&self) -> Option<RGBA>;
     fn get_width(&self) -> LengthOrPercentageOrAuto;
+    fn get_size(&self) -> Option<u32>;
 }
 
 impl HTMLHRLayoutHelpers for LayoutDom<HTMLHRElement> {
@@ -84,6 +91,13 @@ impl HTMLHRLayoutHelpers for LayoutDom<HTMLHRElement> {
                 .unwrap_or(LengthOrPercentageOrAuto::Auto)
         }
     }
+
+    #[allow(unsafe_code)]
+    fn get_size(&self) -> Option<u32> {
+        unsafe {
+            (*self.unsafe_get()).size.get()
+        }
+    }
 }
 
 
@@ -92,6 +106,15 @@ impl VirtualMethods for HTMLHRElement {
         Some(self.upcast::<HTMLElement>() as &VirtualMethods)
     }
 
+    fn attribute_mutated(&self, attr: &Attr, mutation: AttributeMutation) {
+        self.super_type().unwrap().attribute_mutated(attr, mutation);
+        if attr.local_name() == &local_name!("size") {
+            self.size.set(mutation.new_value(attr).and_then(|value| {
+                parse_unsigned_integer(value.chars()).ok()
+            }));
+        }
+    }
+
     fn parse_plain_attribute(&self, name: &LocalName, value: DOMString) -> AttrValue {
         match name {
             &local_name!("align") => AttrValue::from_dimension(value.into()),
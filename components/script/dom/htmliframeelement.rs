@@ -36,7 +36,8 @@ use script_traits::IFrameSandboxState::{IFrameSandboxed, IFrameUnsandboxed};
 use servo_config::prefs::PREFS;
 use servo_url::ServoUrl;
 use std::cell::Cell;
-use style::attr::{AttrValue, LengthOrPercentageOrAuto};
+use style::attr::{AttrValue, LengthOrPercentageOrAuto, parse_integer};
+use style::str::HTML_SPACE_CHARACTERS;
 use task_source::TaskSource;
 
 bitflags! {
@@ -76,6 +77,9 @@ pub struct HTMLIFrameElement {
     sandbox_allowance: Cell<Option<SandboxAllowance>>,
     load_blocker: DomRefCell<Option<LoadBlocker>>,
     visibility: Cell<bool>,
+    /// Whether the legacy `frameborder` attribute asks for a border, parsed
+    /// when it's set. See `get_frame_border`.
+    frame_border: Cell<Option<bool>>,
 }
 
 impl HTMLIFrameElement {
@@ -298,6 +302,7 @@ impl HTMLIFrameElement {
             sandbox_allowance: Cell::new(None),
             load_blocker: DomRefCell::new(None),
             visibility: Cell::new(true),
+            frame_border: Cell::new(None),
         }
     }
 
@@ -367,6 +372,8 @@ pub trait HTMLIFrameElementLayoutMethods {
     fn browsing_context_id(&self) -> Option<BrowsingContextId>;
     fn get_width(&self) -> LengthOrPercentageOrAuto;
     fn get_height(&self) -> LengthOrPercentageOrAuto;
+    fn get_frame_border(&self) -> Option<bool>;
+    fn get_scrolling_disabled(&self) -> bool;
 }
 
 impl HTMLIFrameElementLayoutMethods for LayoutDom<HTMLIFrameElement> {
@@ -408,6 +415,26 @@ impl HTMLIFrameElementLayoutMethods for LayoutDom<HTMLIFrameElement> {
                 .unwrap_or(LengthOrPercentageOrAuto::Auto)
         }
     }
+
+    /// Whether the legacy `frameborder` attribute asks for a border, if it is
+    /// set to a value other engines understand. That is an integer, which
+    /// asks for a border unless it is zero, or `yes` or `no`.
+    #[allow(unsafe_code)]
+    fn get_frame_border(&self) -> Option<bool> {
+        unsafe {
+            (*self.unsafe_get()).frame_border.get()
+        }
+    }
+
+    /// Whether the legacy `scrolling` attribute is set to `no`.
+    #[allow(unsafe_code)]
+    fn get_scrolling_disabled(&self) -> bool {
+        unsafe {
+            (*self.upcast::<Element>().unsafe_get())
+                .get_attr_val_for_layout(&ns!(), &LocalName::from("scrolling"))
+                .map_or(false, |value| value.trim_matches(HTML_SPACE_CHARACTERS).eq_ignore_ascii_case("no"))
+        }
+    }
 }
 
 impl HTMLIFrameElementMethods for HTMLIFrameElement {
@@ -529,6 +556,18 @@ impl VirtualMethods for HTMLIFrameElement {
                 let value = new_value.as_ref().map_or("", |v| &v);
                 self.SetName(DOMString::from(value.to_owned()));
             },
+            &local_name!("frameborder") => {
+                self.frame_border.set(mutation.new_value(attr).and_then(|value| {
+                    match parse_integer(value.chars()) {
+                        Ok(value) => Some(value != 0),
+                        Err(()) => match_ignore_ascii_case! { value.trim_matches(HTML_SPACE_CHARACTERS),
+                            "yes" => Some(true),
+                            "no" => Some(false),
+                            _ => None,
+                        },
+                    }
+                }));
+            },
             _ => {},
         }
     }
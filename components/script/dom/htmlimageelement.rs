@@ -2,7 +2,6 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use app_units::{Au, AU_PER_PX};
 use cssparser::{Parser, ParserInput};
 use document_loader::{LoadType, LoadBlocker};
 use dom::activation::Activatable;
@@ -55,9 +54,9 @@ use servo_url::origin::ImmutableOrigin;
 use std::cell::{Cell, RefMut};
 use std::char;
 use std::default::Default;
-use std::i32;
 use std::sync::{Arc, Mutex};
-use style::attr::{AttrValue, LengthOrPercentageOrAuto, parse_double, parse_unsigned_integer};
+use style::attr::{AttrValue, LengthOrPercentageOrAuto, parse_double, parse_length, parse_unsigned_integer};
+use style::attr::safe_au_from_px;
 use style::context::QuirksMode;
 use style::media_queries::MediaQuery;
 use style::parser::ParserContext;
@@ -125,6 +124,10 @@ pub struct HTMLImageElement {
     pending_request: DomRefCell<ImageRequest>,
     form_owner: MutNullableDom<HTMLFormElement>,
     generation: Cell<u32>,
+    /// The `hspace` and `vspace` attributes parsed as dimensions, since they
+    /// are parsed as integers for the reflected IDL attributes.
+    hspace: Cell<LengthOrPercentageOrAuto>,
+    vspace: Cell<LengthOrPercentageOrAuto>,
 }
 
 impl HTMLImageElement {
@@ -632,6 +635,8 @@ impl HTMLImageElement {
             }),
             form_owner: Default::default(),
             generation: Default::default(),
+            hspace: Cell::new(LengthOrPercentageOrAuto::Auto),
+            vspace: Cell::new(LengthOrPercentageOrAuto::Auto),
         }
     }
 
@@ -722,6 +727,9 @@ pub trait LayoutHTMLImageElementHelpers {
 
     fn get_width(&self) -> LengthOrPercentageOrAuto;
     fn get_height(&self) -> LengthOrPercentageOrAuto;
+    fn get_border(&self) -> Option<u32>;
+    fn get_hspace(&self) -> LengthOrPercentageOrAuto;
+    fn get_vspace(&self) -> LengthOrPercentageOrAuto;
 }
 
 impl LayoutHTMLImageElementHelpers for LayoutDom<HTMLImageElement> {
@@ -756,6 +764,29 @@ impl LayoutHTMLImageElementHelpers for LayoutDom<HTMLImageElement> {
                 .unwrap_or(LengthOrPercentageOrAuto::Auto)
         }
     }
+
+    #[allow(unsafe_code)]
+    fn get_border(&self) -> Option<u32> {
+        unsafe {
+            (*self.upcast::<Element>().unsafe_get())
+                .get_attr_for_layout(&ns!(), &local_name!("border"))
+                .map(AttrValue::as_uint)
+        }
+    }
+
+    #[allow(unsafe_code)]
+    fn get_hspace(&self) -> LengthOrPercentageOrAuto {
+        unsafe {
+            (*self.unsafe_get()).hspace.get()
+        }
+    }
+
+    #[allow(unsafe_code)]
+    fn get_vspace(&self) -> LengthOrPercentageOrAuto {
+        unsafe {
+            (*self.unsafe_get()).vspace.get()
+        }
+    }
 }
 
 //https://html.spec.whatwg.org/multipage/#parse-a-sizes-attribute
@@ -971,6 +1002,16 @@ impl VirtualMethods for HTMLImageElement {
         self.super_type().unwrap().attribute_mutated(attr, mutation);
         match attr.local_name() {
             &local_name!("src") => self.update_the_image_data(),
+            &local_name!("hspace") => {
+                self.hspace.set(mutation.new_value(attr).map_or(LengthOrPercentageOrAuto::Auto, |value| {
+                    parse_length(&value)
+                }));
+            },
+            &local_name!("vspace") => {
+                self.vspace.set(mutation.new_value(attr).map_or(LengthOrPercentageOrAuto::Auto, |value| {
+                    parse_length(&value)
+                }));
+            },
             _ => {},
         }
     }
@@ -978,8 +1019,12 @@ impl VirtualMethods for HTMLImageElement {
     fn parse_plain_attribute(&self, name: &LocalName, value: DOMString) -> AttrValue {
         match name {
             &local_name!("name") => AttrValue::from_atomic(value.into()),
+            // NB: Unlike the non-zero dimension attributes, `0` is a real length
+            // here that collapses the image, while an empty value is `auto`.
             &local_name!("width") | &local_name!("height") => AttrValue::from_dimension(value.into()),
             &local_name!("hspace") | &local_name!("vspace") => AttrValue::from_u32(value.into(), 0),
+            // Invalid values map to a zero border.
+            &local_name!("border") => AttrValue::from_u32(value.into(), 0),
             _ => self.super_type().unwrap().parse_plain_attribute(name, value),
         }
     }
@@ -1048,18 +1093,7 @@ fn image_dimension_setter(element: &Element, attr: LocalName, value: u32) {
         value
     };
 
-    // FIXME: There are probably quite a few more cases of this. This is the
-    // only overflow that was hitting on automation, but we should consider what
-    // to do in the general case case.
-    //
-    // See <https://github.com/servo/app_units/issues/22>
-    let pixel_value = if value > (i32::MAX / AU_PER_PX) as u32 {
-        0
-    } else {
-        value
-    };
-
-    let dim = LengthOrPercentageOrAuto::Length(Au::from_px(pixel_value as i32));
+    let dim = LengthOrPercentageOrAuto::Length(safe_au_from_px(value as i32));
     let value = AttrValue::Dimension(value.to_string(), dim);
     element.set_attribute(&attr, value);
 }
@@ -51,8 +51,9 @@ use script_traits::ScriptToConstellationChan;
 use servo_atoms::Atom;
 use std::borrow::ToOwned;
 use std::cell::Cell;
+use std::cmp;
 use std::ops::Range;
-use style::attr::AttrValue;
+use style::attr::{AttrValue, parse_rendering_size};
 use style::element_state::ElementState;
 use style::str::split_commas;
 use textinput::{Direction, SelectionDirection, TextInput};
@@ -251,6 +252,13 @@ impl InputActivationState {
 }
 
 static DEFAULT_INPUT_SIZE: u32 = 20;
+// The largest size used for rendering. This is way more characters than fit
+// on any screen, and keeps the width computed from it in the range of `Au`
+// even with huge fonts.
+static MAX_INPUT_SIZE: u32 = 10000;
+// The largest size used for rendering an input from its `maxlength`, which
+// is often set much higher than the expected input just as a safeguard.
+static MAX_SIZE_FROM_MAXLENGTH: u32 = 50;
 static DEFAULT_MAX_LENGTH: i32 = -1;
 static DEFAULT_MIN_LENGTH: i32 = -1;
 
@@ -325,8 +333,12 @@ pub trait LayoutHTMLInputElementHelpers {
     #[allow(unsafe_code)]
     unsafe fn value_for_layout(self) -> String;
     #[allow(unsafe_code)]
+    unsafe fn input_type_for_layout(self) -> InputType;
+    #[allow(unsafe_code)]
     unsafe fn size_for_layout(self) -> u32;
     #[allow(unsafe_code)]
+    unsafe fn size_from_maxlength_for_layout(self) -> Option<u32>;
+    #[allow(unsafe_code)]
     unsafe fn selection_for_layout(self) -> Option<Range<usize>>;
     #[allow(unsafe_code)]
     unsafe fn checked_state_for_layout(self) -> bool;
@@ -377,11 +389,33 @@ impl LayoutHTMLInputElementHelpers for LayoutDom<HTMLInputElement> {
     }
 
     #[allow(unrooted_must_root)]
+    #[allow(unsafe_code)]
+    unsafe fn input_type_for_layout(self) -> InputType {
+        (*self.unsafe_get()).input_type.get()
+    }
+
     #[allow(unsafe_code)]
     unsafe fn size_for_layout(self) -> u32 {
         (*self.unsafe_get()).size.get()
     }
 
+    /// The size to render this input with when it has a `maxlength` but no
+    /// `size` attribute, like some legacy engines do.
+    #[allow(unsafe_code)]
+    unsafe fn size_from_maxlength_for_layout(self) -> Option<u32> {
+        let element = &*self.upcast::<Element>().unsafe_get();
+        if element.get_attr_for_layout(&ns!(), &local_name!("size")).is_some() {
+            return None;
+        }
+
+        match element.get_attr_for_layout(&ns!(), &local_name!("maxlength")) {
+            Some(&AttrValue::Int(_, value)) if value > 0 => {
+                Some(cmp::min(value as u32, MAX_SIZE_FROM_MAXLENGTH))
+            },
+            _ => None,
+        }
+    }
+
     #[allow(unrooted_must_root)]
     #[allow(unsafe_code)]
     unsafe fn selection_for_layout(self) -> Option<Range<usize>> {
@@ -1154,8 +1188,10 @@ impl VirtualMethods for HTMLInputElement {
                 self.update_checked_state(checked_state, false);
             },
             &local_name!("size") => {
-                let size = mutation.new_value(attr).map(|value| {
-                    value.as_uint()
+                // This is stricter than the reflected IDL attribute, see
+                // `parse_rendering_size`.
+                let size = mutation.new_value(attr).and_then(|value| {
+                    parse_rendering_size(&value, MAX_INPUT_SIZE).ok()
                 });
                 self.size.set(size.unwrap_or(DEFAULT_INPUT_SIZE));
             }
@@ -2,28 +2,35 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use dom::attr::Attr;
 use dom::bindings::codegen::Bindings::HTMLLIElementBinding;
 use dom::bindings::codegen::Bindings::HTMLLIElementBinding::HTMLLIElementMethods;
 use dom::bindings::inheritance::Castable;
-use dom::bindings::root::DomRoot;
+use dom::bindings::root::{DomRoot, LayoutDom};
 use dom::bindings::str::DOMString;
 use dom::document::Document;
+use dom::element::{AttributeMutation, Element};
 use dom::htmlelement::HTMLElement;
-use dom::node::Node;
+use dom::node::{Node, NodeDamage};
 use dom::virtualmethods::VirtualMethods;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
-use style::attr::AttrValue;
+use std::cell::Cell;
+use style::attr::{AttrValue, parse_integer};
 
 #[dom_struct]
 pub struct HTMLLIElement {
     htmlelement: HTMLElement,
+    /// The `value` attribute, parsed as an integer when it's set, so that
+    /// layout doesn't parse it again.
+    value: Cell<Option<i32>>,
 }
 
 impl HTMLLIElement {
     fn new_inherited(local_name: LocalName, prefix: Option<Prefix>, document: &Document) -> HTMLLIElement {
         HTMLLIElement {
-            htmlelement: HTMLElement::new_inherited(local_name, prefix, document)
+            htmlelement: HTMLElement::new_inherited(local_name, prefix, document),
+            value: Cell::new(None),
         }
     }
 
@@ -45,11 +52,39 @@ impl HTMLLIElementMethods for HTMLLIElement {
     make_int_setter!(SetValue, "value");
 }
 
+pub trait HTMLLIElementLayoutHelpers {
+    fn get_ordinal_value(&self) -> Option<i32>;
+}
+
+#[allow(unsafe_code)]
+impl HTMLLIElementLayoutHelpers for LayoutDom<HTMLLIElement> {
+    // https://html.spec.whatwg.org/multipage/#ordinal-value
+    fn get_ordinal_value(&self) -> Option<i32> {
+        // The attribute is parsed with a default of zero for the IDL
+        // attribute, but an invalid value must not affect the ordinal value.
+        unsafe {
+            (*self.unsafe_get()).value.get()
+        }
+    }
+}
+
 impl VirtualMethods for HTMLLIElement {
     fn super_type(&self) -> Option<&VirtualMethods> {
         Some(self.upcast::<HTMLElement>() as &VirtualMethods)
     }
 
+    fn attribute_mutated(&self, attr: &Attr, mutation: AttributeMutation) {
+        self.super_type().unwrap().attribute_mutated(attr, mutation);
+        if attr.local_name() == &local_name!("value") {
+            self.value.set(mutation.new_value(attr).and_then(|value| {
+                parse_integer(value.chars()).ok()
+            }));
+
+            // Layout reads the ordinal value when it builds the list item.
+            self.upcast::<Element>().restyle(NodeDamage::OtherNodeDamage);
+        }
+    }
+
     fn parse_plain_attribute(&self, name: &LocalName, value: DOMString) -> AttrValue {
         match name {
             &local_name!("value") => AttrValue::from_i32(value.into(), 0),
@@ -26,6 +26,7 @@ use dom::element::{Element, AttributeMutation};
 use dom::eventtarget::EventTarget;
 use dom::htmlelement::HTMLElement;
 use dom::htmlsourceelement::HTMLSourceElement;
+use dom::htmlvideoelement::HTMLVideoElement;
 use dom::mediaerror::MediaError;
 use dom::node::{window_from_node, document_from_node, Node, UnbindContext};
 use dom::promise::Promise;
@@ -46,6 +47,7 @@ use std::collections::VecDeque;
 use std::mem;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
+use style::attr::AttrValue;
 use task_source::TaskSource;
 use time::{self, Timespec, Duration};
 
@@ -926,6 +928,16 @@ impl VirtualMethods for HTMLMediaElement {
         };
     }
 
+    fn parse_plain_attribute(&self, name: &LocalName, value: DOMString) -> AttrValue {
+        match name {
+            // https://html.spec.whatwg.org/multipage/#attr-dim-width
+            &local_name!("width") | &local_name!("height") if self.is::<HTMLVideoElement>() => {
+                AttrValue::from_dimension(value.into())
+            },
+            _ => self.super_type().unwrap().parse_plain_attribute(name, value),
+        }
+    }
+
     // https://html.spec.whatwg.org/multipage/#playing-the-media-resource:remove-an-element-from-a-document
     fn unbind_from_tree(&self, context: &UnbindContext) {
         self.super_type().unwrap().unbind_from_tree(context);
@@ -22,6 +22,7 @@ use html5ever::{LocalName, Prefix};
 use net_traits::image::base::Image;
 use servo_arc::Arc;
 use std::default::Default;
+use style::attr::AttrValue;
 
 #[dom_struct]
 pub struct HTMLObjectElement {
@@ -123,6 +124,14 @@ impl VirtualMethods for HTMLObjectElement {
             _ => {},
         }
     }
+
+    fn parse_plain_attribute(&self, name: &LocalName, value: DOMString) -> AttrValue {
+        match name {
+            &local_name!("width") | &local_name!("height") => AttrValue::from_dimension(value.into()),
+            &local_name!("hspace") | &local_name!("vspace") => AttrValue::from_dimension(value.into()),
+            _ => self.super_type().unwrap().parse_plain_attribute(name, value),
+        }
+    }
 }
 
 impl FormControl for HTMLObjectElement {
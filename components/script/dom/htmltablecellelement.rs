@@ -11,11 +11,13 @@ use dom::bindings::str::DOMString;
 use dom::document::Document;
 use dom::element::{Element, RawLayoutElementHelpers};
 use dom::htmlelement::HTMLElement;
+use dom::htmlimageelement::HTMLImageElement;
 use dom::htmltablerowelement::HTMLTableRowElement;
-use dom::node::Node;
+use dom::node::{ChildrenMutation, Node, NodeDamage, document_from_node};
 use dom::virtualmethods::VirtualMethods;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
+use servo_url::ServoUrl;
 use style::attr::{AttrValue, LengthOrPercentageOrAuto};
 use style::context::QuirksMode;
 
@@ -83,9 +85,11 @@ impl HTMLTableCellElementMethods for HTMLTableCellElement {
 
 pub trait HTMLTableCellElementLayoutHelpers {
     fn get_background_color(&self) -> Option<RGBA>;
+    fn get_background(&self) -> Option<ServoUrl>;
     fn get_colspan(&self) -> Option<u32>;
     fn get_rowspan(&self) -> Option<u32>;
     fn get_width(&self) -> LengthOrPercentageOrAuto;
+    fn get_nowrap(&self) -> bool;
 }
 
 #[allow(unsafe_code)]
@@ -99,6 +103,15 @@ impl HTMLTableCellElementLayoutHelpers for LayoutDom<HTMLTableCellElement> {
         }
     }
 
+    fn get_background(&self) -> Option<ServoUrl> {
+        unsafe {
+            (&*self.upcast::<Element>().unsafe_get())
+                .get_attr_for_layout(&ns!(), &local_name!("background"))
+                .and_then(AttrValue::as_resolved_url)
+                .cloned()
+        }
+    }
+
     fn get_colspan(&self) -> Option<u32> {
         unsafe {
             (&*self.upcast::<Element>().unsafe_get())
@@ -124,6 +137,14 @@ impl HTMLTableCellElementLayoutHelpers for LayoutDom<HTMLTableCellElement> {
                 .unwrap_or(LengthOrPercentageOrAuto::Auto)
         }
     }
+
+    fn get_nowrap(&self) -> bool {
+        unsafe {
+            (&*self.upcast::<Element>().unsafe_get())
+                .get_attr_for_layout(&ns!(), &local_name!("nowrap"))
+                .is_some()
+        }
+    }
 }
 
 impl VirtualMethods for HTMLTableCellElement {
@@ -131,6 +152,26 @@ impl VirtualMethods for HTMLTableCellElement {
         Some(self.upcast::<HTMLElement>() as &VirtualMethods)
     }
 
+    fn children_changed(&self, mutation: &ChildrenMutation) {
+        if let Some(ref s) = self.super_type() {
+            s.children_changed(mutation);
+        }
+
+        // Outside of standards mode, the vertical alignment of an image
+        // depends on whether it is all there is in the cell. This includes
+        // whether the text next to it is whitespace, so changes to the data
+        // of a text child, which arrive as `ChildrenMutation::ChangeText`,
+        // count as well.
+        let node = self.upcast::<Node>();
+        if node.owner_doc().quirks_mode() != QuirksMode::NoQuirks {
+            for child in node.children() {
+                if child.is::<HTMLImageElement>() {
+                    child.dirty(NodeDamage::OtherNodeDamage);
+                }
+            }
+        }
+    }
+
     fn parse_plain_attribute(&self, local_name: &LocalName, value: DOMString) -> AttrValue {
         match *local_name {
             local_name!("colspan") => {
@@ -159,7 +200,11 @@ impl VirtualMethods for HTMLTableCellElement {
                 attr
             }
             local_name!("bgcolor") => AttrValue::from_legacy_color(value.into()),
+            local_name!("background") => {
+                AttrValue::from_resolved_url(&document_from_node(self).base_url(), value.into())
+            },
             local_name!("width") => AttrValue::from_nonzero_dimension(value.into()),
+            local_name!("height") => AttrValue::from_nonzero_dimension(value.into()),
             _ => self.super_type().unwrap().parse_plain_attribute(local_name, value),
         }
     }
@@ -3,12 +3,21 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use dom::bindings::codegen::Bindings::HTMLTableColElementBinding;
-use dom::bindings::root::DomRoot;
+use dom::bindings::inheritance::Castable;
+use dom::bindings::root::{DomRoot, LayoutDom};
+use dom::bindings::str::DOMString;
 use dom::document::Document;
+use dom::element::{Element, RawLayoutElementHelpers};
 use dom::htmlelement::HTMLElement;
 use dom::node::Node;
+use dom::virtualmethods::VirtualMethods;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
+use style::attr::{AttrValue, LengthOrPercentageOrAuto};
+use style::str::HTML_SPACE_CHARACTERS;
+
+const DEFAULT_SPAN: u32 = 1;
+const MAX_SPAN: u32 = 1000;
 
 #[dom_struct]
 pub struct HTMLTableColElement {
@@ -34,3 +43,60 @@ impl HTMLTableColElement {
                            HTMLTableColElementBinding::Wrap)
     }
 }
+
+pub trait HTMLTableColElementLayoutHelpers {
+    fn get_span(&self) -> Option<u32>;
+}
+
+#[allow(unsafe_code)]
+impl HTMLTableColElementLayoutHelpers for LayoutDom<HTMLTableColElement> {
+    fn get_span(&self) -> Option<u32> {
+        unsafe {
+            (&*self.upcast::<Element>().unsafe_get())
+                .get_attr_for_layout(&ns!(), &local_name!("span"))
+                .map(AttrValue::as_uint)
+        }
+    }
+}
+
+/// Whether `value` is a relative length, like `0*` or `3*`, which other
+/// engines give a share of the width left by the other columns.
+fn is_relative_length(value: &str) -> bool {
+    value.trim_left_matches(HTML_SPACE_CHARACTERS)
+        .trim_left_matches(|c: char| c.is_ascii_digit() || c == '.')
+        .starts_with('*')
+}
+
+impl VirtualMethods for HTMLTableColElement {
+    fn super_type(&self) -> Option<&VirtualMethods> {
+        Some(self.upcast::<HTMLElement>() as &VirtualMethods)
+    }
+
+    fn parse_plain_attribute(&self, local_name: &LocalName, value: DOMString) -> AttrValue {
+        match *local_name {
+            // https://html.spec.whatwg.org/multipage/#attr-col-span
+            local_name!("span") => {
+                let mut attr = AttrValue::from_u32(value.into(), DEFAULT_SPAN);
+                if let AttrValue::UInt(ref mut s, ref mut val) = attr {
+                    if *val == 0 || *val > MAX_SPAN {
+                        *val = if *val == 0 { DEFAULT_SPAN } else { MAX_SPAN };
+                        *s = val.to_string();
+                    }
+                }
+                attr
+            }
+            // FIXME: Relative lengths should get a share of the remaining
+            // width of the table, but they are auto for now, which is still
+            // better than parsing `0*` as `0px`.
+            local_name!("width") => {
+                let value = String::from(value);
+                if is_relative_length(&value) {
+                    AttrValue::Dimension(value, LengthOrPercentageOrAuto::Auto)
+                } else {
+                    AttrValue::from_dimension(value)
+                }
+            }
+            _ => self.super_type().unwrap().parse_plain_attribute(local_name, value),
+        }
+    }
+}
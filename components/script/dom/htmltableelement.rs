@@ -17,13 +17,15 @@ use dom::element::{AttributeMutation, Element, RawLayoutElementHelpers};
 use dom::htmlcollection::{CollectionFilter, HTMLCollection};
 use dom::htmlelement::HTMLElement;
 use dom::htmltablecaptionelement::HTMLTableCaptionElement;
+use dom::htmltablecellelement::{HTMLTableCellElement, HTMLTableCellElementLayoutHelpers};
 use dom::htmltablecolelement::HTMLTableColElement;
 use dom::htmltablerowelement::HTMLTableRowElement;
 use dom::htmltablesectionelement::HTMLTableSectionElement;
-use dom::node::{Node, document_from_node, window_from_node};
+use dom::node::{LayoutNodeHelpers, Node, NodeDamage, document_from_node, window_from_node};
 use dom::virtualmethods::VirtualMethods;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
+use servo_url::ServoUrl;
 use std::cell::Cell;
 use style::attr::{AttrValue, LengthOrPercentageOrAuto, parse_unsigned_integer};
 
@@ -32,6 +34,7 @@ pub struct HTMLTableElement {
     htmlelement: HTMLElement,
     border: Cell<Option<u32>>,
     cellspacing: Cell<Option<u32>>,
+    cellpadding: Cell<Option<u32>>,
     tbodies: MutNullableDom<HTMLCollection>,
 }
 
@@ -56,6 +59,7 @@ impl HTMLTableElement {
             htmlelement: HTMLElement::new_inherited(local_name, prefix, document),
             border: Cell::new(None),
             cellspacing: Cell::new(None),
+            cellpadding: Cell::new(None),
             tbodies: Default::default(),
         }
     }
@@ -374,9 +378,14 @@ impl HTMLTableElementMethods for HTMLTableElement {
 
 pub trait HTMLTableElementLayoutHelpers {
     fn get_background_color(&self) -> Option<RGBA>;
+    fn get_background(&self) -> Option<ServoUrl>;
     fn get_border(&self) -> Option<u32>;
     fn get_cellspacing(&self) -> Option<u32>;
+    fn get_cellpadding(&self) -> Option<u32>;
     fn get_width(&self) -> LengthOrPercentageOrAuto;
+    fn get_height(&self) -> LengthOrPercentageOrAuto;
+    fn get_cell_count(&self) -> usize;
+    fn has_explicit_column_widths(&self) -> bool;
 }
 
 impl HTMLTableElementLayoutHelpers for LayoutDom<HTMLTableElement> {
@@ -390,6 +399,16 @@ impl HTMLTableElementLayoutHelpers for LayoutDom<HTMLTableElement> {
         }
     }
 
+    #[allow(unsafe_code)]
+    fn get_background(&self) -> Option<ServoUrl> {
+        unsafe {
+            (*self.upcast::<Element>().unsafe_get())
+                .get_attr_for_layout(&ns!(), &local_name!("background"))
+                .and_then(AttrValue::as_resolved_url)
+                .cloned()
+        }
+    }
+
     #[allow(unsafe_code)]
     fn get_border(&self) -> Option<u32> {
         unsafe {
@@ -404,6 +423,13 @@ impl HTMLTableElementLayoutHelpers for LayoutDom<HTMLTableElement> {
         }
     }
 
+    #[allow(unsafe_code)]
+    fn get_cellpadding(&self) -> Option<u32> {
+        unsafe {
+            (*self.unsafe_get()).cellpadding.get()
+        }
+    }
+
     #[allow(unsafe_code)]
     fn get_width(&self) -> LengthOrPercentageOrAuto {
         unsafe {
@@ -414,6 +440,100 @@ impl HTMLTableElementLayoutHelpers for LayoutDom<HTMLTableElement> {
                 .unwrap_or(LengthOrPercentageOrAuto::Auto)
         }
     }
+
+    #[allow(unsafe_code)]
+    fn get_height(&self) -> LengthOrPercentageOrAuto {
+        unsafe {
+            (*self.upcast::<Element>().unsafe_get())
+                .get_attr_for_layout(&ns!(), &local_name!("height"))
+                .map(AttrValue::as_dimension)
+                .cloned()
+                .unwrap_or(LengthOrPercentageOrAuto::Auto)
+        }
+    }
+
+    /// Counts the cells in the rows that belong to this table, either directly or
+    /// through its row groups. Nested tables are not counted.
+    #[allow(unsafe_code)]
+    fn get_cell_count(&self) -> usize {
+        #[allow(unsafe_code)]
+        unsafe fn count_row_cells(row: LayoutDom<Node>) -> usize {
+            let mut count = 0;
+            let mut child = row.first_child_ref();
+            while let Some(cell) = child {
+                if cell.downcast::<HTMLTableCellElement>().is_some() {
+                    count += 1;
+                }
+                child = cell.next_sibling_ref();
+            }
+            count
+        }
+
+        unsafe {
+            let mut count = 0;
+            let mut child = self.upcast::<Node>().first_child_ref();
+            while let Some(node) = child {
+                if node.downcast::<HTMLTableRowElement>().is_some() {
+                    count += count_row_cells(node);
+                } else if node.downcast::<HTMLTableSectionElement>().is_some() {
+                    let mut section_child = node.first_child_ref();
+                    while let Some(row) = section_child {
+                        if row.downcast::<HTMLTableRowElement>().is_some() {
+                            count += count_row_cells(row);
+                        }
+                        section_child = row.next_sibling_ref();
+                    }
+                }
+                child = node.next_sibling_ref();
+            }
+            count
+        }
+    }
+
+    /// Whether all the cells of the first row of this table have a `width`
+    /// attribute, which then determines the widths of the columns.
+    #[allow(unsafe_code)]
+    fn has_explicit_column_widths(&self) -> bool {
+        unsafe fn first_row(table: LayoutDom<Node>) -> Option<LayoutDom<Node>> {
+            let mut child = table.first_child_ref();
+            while let Some(node) = child {
+                if node.downcast::<HTMLTableRowElement>().is_some() {
+                    return Some(node);
+                }
+                if node.downcast::<HTMLTableSectionElement>().is_some() {
+                    let mut section_child = node.first_child_ref();
+                    while let Some(row) = section_child {
+                        if row.downcast::<HTMLTableRowElement>().is_some() {
+                            return Some(row);
+                        }
+                        section_child = row.next_sibling_ref();
+                    }
+                }
+                child = node.next_sibling_ref();
+            }
+            None
+        }
+
+        unsafe {
+            let row = match first_row(self.upcast::<Node>()) {
+                Some(row) => row,
+                None => return false,
+            };
+
+            let mut has_cells = false;
+            let mut child = row.first_child_ref();
+            while let Some(node) = child {
+                if let Some(cell) = node.downcast::<HTMLTableCellElement>() {
+                    if cell.get_width() == LengthOrPercentageOrAuto::Auto {
+                        return false;
+                    }
+                    has_cells = true;
+                }
+                child = node.next_sibling_ref();
+            }
+            has_cells
+        }
+    }
 }
 
 impl VirtualMethods for HTMLTableElement {
@@ -435,6 +555,21 @@ impl VirtualMethods for HTMLTableElement {
                     parse_unsigned_integer(value.chars()).ok()
                 }));
             },
+            local_name!("cellpadding") => {
+                self.cellpadding.set(mutation.new_value(attr).and_then(|value| {
+                    parse_unsigned_integer(value.chars()).ok()
+                }));
+
+                // The padding of the cells in the rows of the table comes
+                // from this attribute.
+                for row in self.Rows().elements_iter() {
+                    for cell in row.upcast::<Node>().children().filter_map(DomRoot::downcast::<Element>) {
+                        if cell.is::<HTMLTableCellElement>() {
+                            cell.restyle(NodeDamage::OtherNodeDamage);
+                        }
+                    }
+                }
+            },
             _ => {},
         }
     }
@@ -443,7 +578,11 @@ impl VirtualMethods for HTMLTableElement {
         match *local_name {
             local_name!("border") => AttrValue::from_u32(value.into(), 1),
             local_name!("width") => AttrValue::from_nonzero_dimension(value.into()),
+            local_name!("height") => AttrValue::from_nonzero_dimension(value.into()),
             local_name!("bgcolor") => AttrValue::from_legacy_color(value.into()),
+            local_name!("background") => {
+                AttrValue::from_resolved_url(&document_from_node(self).base_url(), value.into())
+            },
             _ => self.super_type().unwrap().parse_plain_attribute(local_name, value),
         }
     }
@@ -19,10 +19,11 @@ use dom::htmltabledatacellelement::HTMLTableDataCellElement;
 use dom::htmltableelement::HTMLTableElement;
 use dom::htmltableheadercellelement::HTMLTableHeaderCellElement;
 use dom::htmltablesectionelement::HTMLTableSectionElement;
-use dom::node::{Node, window_from_node};
+use dom::node::{Node, document_from_node, window_from_node};
 use dom::virtualmethods::VirtualMethods;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
+use servo_url::ServoUrl;
 use style::attr::AttrValue;
 
 #[derive(JSTraceable)]
@@ -139,6 +140,7 @@ impl HTMLTableRowElementMethods for HTMLTableRowElement {
 
 pub trait HTMLTableRowElementLayoutHelpers {
     fn get_background_color(&self) -> Option<RGBA>;
+    fn get_background(&self) -> Option<ServoUrl>;
 }
 
 #[allow(unsafe_code)]
@@ -151,6 +153,15 @@ impl HTMLTableRowElementLayoutHelpers for LayoutDom<HTMLTableRowElement> {
                 .cloned()
         }
     }
+
+    fn get_background(&self) -> Option<ServoUrl> {
+        unsafe {
+            (&*self.upcast::<Element>().unsafe_get())
+                .get_attr_for_layout(&ns!(), &local_name!("background"))
+                .and_then(AttrValue::as_resolved_url)
+                .cloned()
+        }
+    }
 }
 
 impl VirtualMethods for HTMLTableRowElement {
@@ -161,6 +172,10 @@ impl VirtualMethods for HTMLTableRowElement {
     fn parse_plain_attribute(&self, local_name: &LocalName, value: DOMString) -> AttrValue {
         match *local_name {
             local_name!("bgcolor") => AttrValue::from_legacy_color(value.into()),
+            local_name!("background") => {
+                AttrValue::from_resolved_url(&document_from_node(self).base_url(), value.into())
+            },
+            local_name!("height") => AttrValue::from_nonzero_dimension(value.into()),
             _ => self.super_type().unwrap().parse_plain_attribute(local_name, value),
         }
     }
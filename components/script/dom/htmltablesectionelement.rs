@@ -14,10 +14,11 @@ use dom::element::{Element, RawLayoutElementHelpers};
 use dom::htmlcollection::{CollectionFilter, HTMLCollection};
 use dom::htmlelement::HTMLElement;
 use dom::htmltablerowelement::HTMLTableRowElement;
-use dom::node::{Node, window_from_node};
+use dom::node::{Node, document_from_node, window_from_node};
 use dom::virtualmethods::VirtualMethods;
 use dom_struct::dom_struct;
 use html5ever::{LocalName, Prefix};
+use servo_url::ServoUrl;
 use style::attr::AttrValue;
 
 #[dom_struct]
@@ -78,6 +79,7 @@ impl HTMLTableSectionElementMethods for HTMLTableSectionElement {
 
 pub trait HTMLTableSectionElementLayoutHelpers {
     fn get_background_color(&self) -> Option<RGBA>;
+    fn get_background(&self) -> Option<ServoUrl>;
 }
 
 #[allow(unsafe_code)]
@@ -90,6 +92,15 @@ impl HTMLTableSectionElementLayoutHelpers for LayoutDom<HTMLTableSectionElement>
                 .cloned()
         }
     }
+
+    fn get_background(&self) -> Option<ServoUrl> {
+        unsafe {
+            (&*self.upcast::<Element>().unsafe_get())
+                .get_attr_for_layout(&ns!(), &local_name!("background"))
+                .and_then(AttrValue::as_resolved_url)
+                .cloned()
+        }
+    }
 }
 
 impl VirtualMethods for HTMLTableSectionElement {
@@ -100,6 +111,9 @@ impl VirtualMethods for HTMLTableSectionElement {
     fn parse_plain_attribute(&self, local_name: &LocalName, value: DOMString) -> AttrValue {
         match *local_name {
             local_name!("bgcolor") => AttrValue::from_legacy_color(value.into()),
+            local_name!("background") => {
+                AttrValue::from_resolved_url(&document_from_node(self).base_url(), value.into())
+            },
             _ => self.super_type().unwrap().parse_plain_attribute(local_name, value),
         }
     }
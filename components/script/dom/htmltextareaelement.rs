@@ -15,7 +15,7 @@ use dom::bindings::root::{DomRoot, LayoutDom, MutNullableDom};
 use dom::bindings::str::DOMString;
 use dom::document::Document;
 use dom::element::{AttributeMutation, Element};
-use dom::element::RawLayoutElementHelpers;
+use dom::element::{LayoutElementHelpers, RawLayoutElementHelpers};
 use dom::event::{Event, EventBubbles, EventCancelable};
 use dom::globalscope::GlobalScope;
 use dom::htmlelement::HTMLElement;
@@ -58,6 +58,8 @@ pub trait LayoutHTMLTextAreaElementHelpers {
     fn get_cols(self) -> u32;
     #[allow(unsafe_code)]
     fn get_rows(self) -> u32;
+    #[allow(unsafe_code)]
+    fn is_resizable(self) -> bool;
 }
 
 impl LayoutHTMLTextAreaElementHelpers for LayoutDom<HTMLTextAreaElement> {
@@ -102,6 +104,18 @@ impl LayoutHTMLTextAreaElementHelpers for LayoutDom<HTMLTextAreaElement> {
                 .map_or(DEFAULT_ROWS, AttrValue::as_uint)
         }
     }
+
+    /// Whether the user can resize the text area, which only makes sense for
+    /// a mutable one. This uses the disabled state rather than the attribute,
+    /// so that a disabled fieldset ancestor counts.
+    #[allow(unsafe_code)]
+    fn is_resizable(self) -> bool {
+        unsafe {
+            let element = self.upcast::<Element>();
+            !element.get_state_for_layout().contains(ElementState::IN_DISABLED_STATE) &&
+                (*element.unsafe_get()).get_attr_for_layout(&ns!(), &local_name!("readonly")).is_none()
+        }
+    }
 }
 
 // https://html.spec.whatwg.org/multipage/#attr-textarea-cols-value
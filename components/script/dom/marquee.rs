@@ -0,0 +1,52 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The attributes of `marquee` elements that their presentational hints
+//! depend on.
+//!
+//! Servo doesn't animate `marquee` elements yet, so only the direction is
+//! parsed, for the default height of vertical ones.
+//!
+//! https://html.spec.whatwg.org/multipage/#the-marquee-element-2
+
+use dom::bindings::root::LayoutDom;
+use dom::element::{Element, RawLayoutElementHelpers};
+use html5ever::LocalName;
+
+/// The direction the contents of a `marquee` element move in.
+///
+/// https://html.spec.whatwg.org/multipage/#attr-marquee-direction
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MarqueeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl MarqueeDirection {
+    /// Parses the `direction` attribute, ignoring invalid values.
+    pub fn from_attribute(value: Option<&str>) -> MarqueeDirection {
+        value.and_then(|value| Some(match_ignore_ascii_case! { value,
+            "left" => MarqueeDirection::Left,
+            "right" => MarqueeDirection::Right,
+            "up" => MarqueeDirection::Up,
+            "down" => MarqueeDirection::Down,
+            _ => return None,
+        })).unwrap_or(MarqueeDirection::Left)
+    }
+
+    /// Whether the contents move vertically.
+    pub fn is_vertical(&self) -> bool {
+        *self == MarqueeDirection::Up || *self == MarqueeDirection::Down
+    }
+}
+
+/// Parses the direction of the `marquee` element `element`.
+#[allow(unsafe_code)]
+pub unsafe fn parse_marquee_direction(element: &LayoutDom<Element>) -> MarqueeDirection {
+    let element = &*element.unsafe_get();
+    MarqueeDirection::from_attribute(element.get_attr_val_for_layout(&ns!(), &LocalName::from("direction")))
+}
+
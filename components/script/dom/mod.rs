@@ -376,6 +376,7 @@ pub mod imagedata;
 pub mod inputevent;
 pub mod keyboardevent;
 pub mod location;
+pub mod marquee;
 pub mod mediaerror;
 pub mod medialist;
 pub mod mediaquerylist;
@@ -409,6 +410,7 @@ pub mod permissionstatus;
 pub mod plugin;
 pub mod pluginarray;
 pub mod popstateevent;
+pub mod presentational_hints;
 pub mod processinginstruction;
 pub mod progressevent;
 pub mod promise;
@@ -164,7 +164,13 @@ bitflags! {
                  to be reachable with using sequential focus navigation."]
         const SEQUENTIALLY_FOCUSABLE = 1 << 3;
 
-        // There are two free bits here.
+        /// Whether this element has an attribute that may map to a
+        /// presentational hint.
+        ///
+        /// See `Element::attribute_affects_presentational_hints`.
+        const HAS_PRESENTATIONAL_HINT_ATTRIBUTES = 1 << 4;
+
+        // There is one free bit here.
 
         #[doc = "Specifies whether the parser has set an associated form owner for \
                  this element. Only applicable for form-associatable elements."]
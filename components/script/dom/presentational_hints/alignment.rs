@@ -0,0 +1,435 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The presentational hints for the alignment of elements and of their
+//! contents, along with the floats that `align` attributes map to.
+
+use dom::bindings::inheritance::{Castable, CharacterDataTypeId, NodeTypeId};
+use dom::bindings::root::LayoutDom;
+use dom::characterdata::{CharacterData, LayoutCharacterDataHelpers};
+use dom::document::LayoutDocumentHelpers;
+use dom::element::{Element, RawLayoutElementHelpers};
+use dom::htmlimageelement::{HTMLImageElement, LayoutHTMLImageElementHelpers};
+use dom::htmltablecellelement::HTMLTableCellElement;
+use dom::node::{LayoutNodeHelpers, Node};
+use html5ever::LocalName;
+use style::attr::LengthOrPercentageOrAuto;
+use style::context::QuirksMode;
+use style::properties::PropertyDeclaration;
+use style::properties::longhands::{clear, float};
+use style::str::is_whitespace;
+use style::stylist::Stylist;
+use style::values::{CSSFloat, specified};
+use super::{HintContext, HintHandler};
+
+/// The gap between an image floated by its `align` attribute and the text
+/// next to it, in quirks mode.
+const IMAGE_FLOAT_QUIRK_GAP: CSSFloat = 3.;
+
+pub const HANDLERS: &'static [HintHandler] = &[
+    HintHandler {
+        local_names: &["table"],
+        attributes: &["align"],
+        without_attributes: false,
+        synthesize: table,
+    },
+    HintHandler {
+        local_names: &["hr"],
+        attributes: &["align"],
+        without_attributes: false,
+        synthesize: hr,
+    },
+    HintHandler {
+        local_names: &["img"],
+        attributes: &["align", "hspace"],
+        without_attributes: true,
+        synthesize: img,
+    },
+    HintHandler {
+        local_names: &["embed", "iframe", "object"],
+        attributes: &["align"],
+        without_attributes: false,
+        synthesize: embedded_content,
+    },
+    HintHandler {
+        local_names: &["br"],
+        attributes: &["clear"],
+        without_attributes: false,
+        synthesize: br,
+    },
+    HintHandler {
+        local_names: &["tbody", "td", "tfoot", "th", "thead", "tr"],
+        attributes: &["align", "valign"],
+        without_attributes: false,
+        synthesize: table_content,
+    },
+    HintHandler {
+        local_names: &["div"],
+        attributes: &["align"],
+        without_attributes: false,
+        synthesize: div,
+    },
+    HintHandler {
+        local_names: &["h1", "h2", "h3", "h4", "h5", "h6", "p"],
+        attributes: &["align"],
+        without_attributes: false,
+        synthesize: paragraph,
+    },
+    HintHandler {
+        local_names: &["center"],
+        attributes: &[],
+        without_attributes: true,
+        synthesize: center,
+    },
+];
+
+/// A centered table only moves if it is narrower than its container, like any
+/// block with auto margins.
+///
+/// https://html.spec.whatwg.org/multipage/#tables-2
+#[allow(unsafe_code)]
+unsafe fn table(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let logical = cx.stylist.logical_alignment_hints();
+    if let Some(align) = get_attribute(cx.element, &local_name!("align")) {
+        match_ignore_ascii_case! { align,
+            "left" => push_hint(
+                Some("align"),
+                logical_float(PropertyDeclaration::Float(float::SpecifiedValue::Left), logical)),
+            "right" => push_hint(
+                Some("align"),
+                logical_float(PropertyDeclaration::Float(float::SpecifiedValue::Right), logical)),
+            "center" => {
+                push_hint(
+                    Some("align"),
+                    PropertyDeclaration::MarginLeft(specified::LengthOrPercentageOrAuto::Auto));
+                push_hint(
+                    Some("align"),
+                    PropertyDeclaration::MarginRight(specified::LengthOrPercentageOrAuto::Auto));
+            },
+            _ => {},
+        }
+    }
+}
+
+/// A rule without a `width` fills its container, so that its alignment margins
+/// resolve to zero and don't move it; they only matter once the rule is
+/// narrower, which may also be due to author style.
+///
+/// https://html.spec.whatwg.org/multipage/#the-hr-element-2
+#[allow(unsafe_code)]
+unsafe fn hr(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let margins = get_attribute(cx.element, &local_name!("align")).and_then(rule_alignment_margins);
+    if let Some((margin_left, margin_right)) = margins {
+        if cx.stylist.logical_alignment_hints() {
+            push_hint(
+                Some("align"),
+                PropertyDeclaration::MarginInlineStart(margin_left));
+            push_hint(
+                Some("align"),
+                PropertyDeclaration::MarginInlineEnd(margin_right));
+        } else {
+            push_hint(
+                Some("align"),
+                PropertyDeclaration::MarginLeft(margin_left));
+            push_hint(
+                Some("align"),
+                PropertyDeclaration::MarginRight(margin_right));
+        }
+    }
+}
+
+#[allow(unsafe_code)]
+unsafe fn img(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLImageElement>().unwrap();
+    let align = get_attribute(cx.element, &local_name!("align"));
+    push_embedded_content_alignment(align, cx.stylist, push_hint);
+
+    // In quirks mode, text doesn't touch an image floated by its `align`
+    // attribute. The gap is a rule of the user agent style sheet in the spec,
+    // so an `hspace` attribute replaces it.
+    //
+    // https://html.spec.whatwg.org/multipage/#images-3
+    if cx.document.quirks_mode() == QuirksMode::Quirks && this.get_hspace() == LengthOrPercentageOrAuto::Auto {
+        if let Some(align) = align {
+            let gap = specified::LengthOrPercentageOrAuto::Length(
+                specified::NoCalcLength::Absolute(specified::AbsoluteLength::Px(IMAGE_FLOAT_QUIRK_GAP)));
+            let logical = cx.stylist.logical_alignment_hints();
+            if align.eq_ignore_ascii_case("left") {
+                push_hint(Some("align"), if logical {
+                    PropertyDeclaration::MarginInlineEnd(gap)
+                } else {
+                    PropertyDeclaration::MarginRight(gap)
+                });
+            } else if align.eq_ignore_ascii_case("right") {
+                push_hint(Some("align"), if logical {
+                    PropertyDeclaration::MarginInlineStart(gap)
+                } else {
+                    PropertyDeclaration::MarginLeft(gap)
+                });
+            }
+        }
+    }
+
+    // Other engines leave no gap below an image that is all there is in a
+    // table cell outside of standards mode, which old layouts made of sliced
+    // images rely on.
+    //
+    // https://quirks.spec.whatwg.org/#the-line-height-calculation-quirk
+    if align.is_none() &&
+        cx.document.quirks_mode() != QuirksMode::NoQuirks &&
+        is_sole_content_of_table_cell(cx.element)
+    {
+        push_hint(None, PropertyDeclaration::VerticalAlign(specified::VerticalAlign::Bottom));
+    }
+}
+
+#[allow(unsafe_code)]
+unsafe fn embedded_content(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_embedded_content_alignment(get_attribute(cx.element, &local_name!("align")), cx.stylist, push_hint);
+}
+
+/// https://html.spec.whatwg.org/multipage/#phrasing-content-3
+#[allow(unsafe_code)]
+unsafe fn br(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    if let Some(clear) = get_attribute(cx.element, &local_name!("clear")).and_then(line_break_clear) {
+        push_hint(
+            Some("clear"),
+            PropertyDeclaration::Clear(clear));
+    }
+}
+
+#[allow(unsafe_code)]
+unsafe fn table_content(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    // `text-align` is inherited, so the alignment of a row group or row also
+    // applies to the cells in it without one of their own, and the closest
+    // `align` attribute wins. The `align` attribute of `col` elements is not
+    // a presentational hint.
+    //
+    // https://html.spec.whatwg.org/multipage/#tables-2
+    if let Some(text_align) = get_attribute(cx.element, &local_name!("align")).and_then(table_content_alignment) {
+        let text_align = logical_text_alignment(text_align, cx.stylist.logical_alignment_hints());
+        push_hint(
+            Some("align"),
+            PropertyDeclaration::TextAlign(specified::TextAlign::Keyword(text_align)));
+    }
+
+    // The default vertical alignment of cells is inherited from their row
+    // group through the user agent stylesheet, so only explicit `valign`
+    // attributes are hints, and those of rows and row groups are inherited by
+    // the cells in them without one of their own.
+    //
+    // https://html.spec.whatwg.org/multipage/#tables-2
+    let vertical_align = get_attribute(cx.element, &local_name!("valign")).and_then(table_content_vertical_alignment);
+    if let Some(vertical_align) = vertical_align {
+        push_hint(
+            Some("valign"),
+            PropertyDeclaration::VerticalAlign(vertical_align));
+    }
+}
+
+/// https://html.spec.whatwg.org/multipage/#flow-content-3
+#[allow(unsafe_code)]
+unsafe fn div(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    if let Some(text_align) = get_attribute(cx.element, &local_name!("align")).and_then(block_alignment) {
+        let text_align = logical_text_alignment(text_align, cx.stylist.logical_alignment_hints());
+        push_hint(
+            Some("align"),
+            PropertyDeclaration::TextAlign(specified::TextAlign::Keyword(text_align)));
+    }
+}
+
+/// Unlike the one of `div` elements, the alignment of paragraphs and headings
+/// doesn't apply to the blocks in them.
+///
+/// https://html.spec.whatwg.org/multipage/#flow-content-3
+#[allow(unsafe_code)]
+unsafe fn paragraph(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    if let Some(text_align) = get_attribute(cx.element, &local_name!("align")).and_then(paragraph_alignment) {
+        let text_align = logical_text_alignment(text_align, cx.stylist.logical_alignment_hints());
+        push_hint(
+            Some("align"),
+            PropertyDeclaration::TextAlign(specified::TextAlign::Keyword(text_align)));
+    }
+}
+
+/// https://html.spec.whatwg.org/multipage/#flow-content-3
+///
+/// `display: block` already comes from the user-agent stylesheet. The
+/// servo-specific keyword also aligns descendant blocks to the center, which
+/// is the same in both directions, so logical alignment hints don't apply.
+#[allow(unsafe_code)]
+unsafe fn center(_cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_hint(
+        None,
+        PropertyDeclaration::TextAlign(specified::TextAlign::Keyword(specified::TextAlignKeyword::ServoCenter)));
+}
+
+/// The value of the `name` attribute of `element`, if any.
+#[allow(unsafe_code)]
+unsafe fn get_attribute<'a>(element: &'a LayoutDom<Element>, name: &LocalName) -> Option<&'a str> {
+    (*element.unsafe_get()).get_attr_val_for_layout(&ns!(), name)
+}
+
+/// https://html.spec.whatwg.org/multipage/#attributes-for-embedded-content-and-images
+fn push_embedded_content_alignment(align: Option<&str>,
+                                   stylist: &Stylist,
+                                   push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    if let Some(declaration) = align.and_then(embedded_content_alignment) {
+        push_hint(Some("align"), logical_float(declaration, stylist.logical_alignment_hints()));
+    }
+}
+
+/// Maps the `align` attribute of table row groups, rows and cells to a
+/// horizontal alignment. The `absmiddle` keyword is handled in the
+/// presentational hints stylesheet.
+fn table_content_alignment(align: &str) -> Option<specified::TextAlignKeyword> {
+    Some(match_ignore_ascii_case! { align,
+        "left" => specified::TextAlignKeyword::ServoLeft,
+        "right" => specified::TextAlignKeyword::ServoRight,
+        "center" | "middle" => specified::TextAlignKeyword::ServoCenter,
+        "justify" => specified::TextAlignKeyword::Justify,
+        _ => return None,
+    })
+}
+
+/// Maps the `valign` attribute of table row groups, rows and cells to a
+/// vertical alignment.
+fn table_content_vertical_alignment(valign: &str) -> Option<specified::VerticalAlign> {
+    Some(match_ignore_ascii_case! { valign,
+        "top" => specified::VerticalAlign::Top,
+        "middle" => specified::VerticalAlign::Middle,
+        "bottom" => specified::VerticalAlign::Bottom,
+        "baseline" => specified::VerticalAlign::Baseline,
+        _ => return None,
+    })
+}
+
+/// Maps the `align` attribute of paragraphs and headings to a horizontal
+/// alignment of their inline content.
+fn paragraph_alignment(align: &str) -> Option<specified::TextAlignKeyword> {
+    Some(match_ignore_ascii_case! { align,
+        "left" => specified::TextAlignKeyword::Left,
+        "right" => specified::TextAlignKeyword::Right,
+        "center" => specified::TextAlignKeyword::Center,
+        "justify" => specified::TextAlignKeyword::Justify,
+        _ => return None,
+    })
+}
+
+/// Maps the `align` attribute of `div` elements to a horizontal alignment.
+fn block_alignment(align: &str) -> Option<specified::TextAlignKeyword> {
+    Some(match_ignore_ascii_case! { align,
+        "left" => specified::TextAlignKeyword::ServoLeft,
+        "right" => specified::TextAlignKeyword::ServoRight,
+        "center" | "middle" => specified::TextAlignKeyword::ServoCenter,
+        "justify" => specified::TextAlignKeyword::Justify,
+        _ => return None,
+    })
+}
+
+/// The `left` and `right` values of `align` attributes are physical, unless
+/// the stylist asks for logical alignment hints, in which case they map to
+/// `start` and `end` and so follow the direction of the element.
+fn logical_text_alignment(text_align: specified::TextAlignKeyword,
+                          logical: bool)
+                          -> specified::TextAlignKeyword {
+    if !logical {
+        return text_align;
+    }
+    match text_align {
+        specified::TextAlignKeyword::Left |
+        specified::TextAlignKeyword::ServoLeft => specified::TextAlignKeyword::Start,
+        specified::TextAlignKeyword::Right |
+        specified::TextAlignKeyword::ServoRight => specified::TextAlignKeyword::End,
+        text_align => text_align,
+    }
+}
+
+/// Like `logical_text_alignment`, for the floats that `align` attributes map
+/// to.
+fn logical_float(declaration: PropertyDeclaration, logical: bool) -> PropertyDeclaration {
+    match declaration {
+        PropertyDeclaration::Float(float::SpecifiedValue::Left) if logical => {
+            PropertyDeclaration::Float(float::SpecifiedValue::InlineStart)
+        },
+        PropertyDeclaration::Float(float::SpecifiedValue::Right) if logical => {
+            PropertyDeclaration::Float(float::SpecifiedValue::InlineEnd)
+        },
+        declaration => declaration,
+    }
+}
+
+/// Maps the `align` attribute of `hr` elements to its left and right margins.
+fn rule_alignment_margins(align: &str)
+                          -> Option<(specified::LengthOrPercentageOrAuto, specified::LengthOrPercentageOrAuto)> {
+    let zero = specified::LengthOrPercentageOrAuto::Length(
+        specified::NoCalcLength::Absolute(specified::AbsoluteLength::Px(0.)));
+    let auto = specified::LengthOrPercentageOrAuto::Auto;
+    Some(match_ignore_ascii_case! { align,
+        "left" => (zero, auto),
+        "right" => (auto, zero),
+        "center" => (auto.clone(), auto),
+        _ => return None,
+    })
+}
+
+/// Whether `element` is the only content of a table cell, not counting
+/// whitespace and comments.
+#[allow(unsafe_code)]
+unsafe fn is_sole_content_of_table_cell(element: &LayoutDom<Element>) -> bool {
+    let node = element.upcast::<Node>();
+    let cell = match node.parent_node_ref() {
+        Some(parent) if parent.downcast::<HTMLTableCellElement>().is_some() => parent,
+        _ => return false,
+    };
+
+    let mut child = cell.first_child_ref();
+    while let Some(current) = child {
+        let ignored = match current.type_id_for_layout() {
+            NodeTypeId::CharacterData(CharacterDataTypeId::Comment) => true,
+            NodeTypeId::CharacterData(CharacterDataTypeId::Text) => {
+                is_whitespace(current.downcast::<CharacterData>().unwrap().data_for_layout())
+            },
+            _ => current == node,
+        };
+        if !ignored {
+            return false;
+        }
+        child = current.next_sibling_ref();
+    }
+    true
+}
+
+/// The `clear` value that the `clear` attribute of a `br` element maps to, if
+/// any. Unlike in other engines, `none` is a hint too, so that it overrides
+/// the user agent stylesheet like the other values.
+///
+/// https://html.spec.whatwg.org/multipage/#phrasing-content-3
+pub fn line_break_clear(clear: &str) -> Option<clear::SpecifiedValue> {
+    Some(match_ignore_ascii_case! { clear,
+        "left" => clear::SpecifiedValue::Left,
+        "right" => clear::SpecifiedValue::Right,
+        "all" | "both" => clear::SpecifiedValue::Both,
+        "none" => clear::SpecifiedValue::None,
+        _ => return None,
+    })
+}
+
+/// Maps the legacy `align` attribute of images and embedded content to either
+/// a float or a vertical alignment. Unknown keywords produce no hint.
+///
+/// https://html.spec.whatwg.org/multipage/#attributes-for-embedded-content-and-images
+pub fn embedded_content_alignment(align: &str) -> Option<PropertyDeclaration> {
+    let vertical_align = match_ignore_ascii_case! { align,
+        "left" => return Some(PropertyDeclaration::Float(float::SpecifiedValue::Left)),
+        "right" => return Some(PropertyDeclaration::Float(float::SpecifiedValue::Right)),
+        "top" => specified::VerticalAlign::Top,
+        "texttop" => specified::VerticalAlign::TextTop,
+        "middle" | "center" | "absmiddle" | "abscenter" => specified::VerticalAlign::Middle,
+        "bottom" | "baseline" => specified::VerticalAlign::Baseline,
+        "absbottom" => specified::VerticalAlign::Bottom,
+        _ => return None,
+    };
+    Some(PropertyDeclaration::VerticalAlign(vertical_align))
+}
@@ -0,0 +1,162 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The presentational hints for the borders of rules, tables, images and
+//! frames.
+
+use dom::bindings::inheritance::Castable;
+use dom::element::RawLayoutElementHelpers;
+use dom::htmlhrelement::{HTMLHRElement, HTMLHRLayoutHelpers};
+use dom::htmliframeelement::{HTMLIFrameElement, HTMLIFrameElementLayoutMethods};
+use dom::htmlimageelement::{HTMLImageElement, LayoutHTMLImageElementHelpers};
+use dom::htmltableelement::{HTMLTableElement, HTMLTableElementLayoutHelpers};
+use style::properties::PropertyDeclaration;
+use style::values::{CSSFloat, specified};
+use super::{HintContext, HintHandler};
+
+pub const HANDLERS: &'static [HintHandler] = &[
+    HintHandler {
+        local_names: &["hr"],
+        attributes: &["noshade", "color", "size"],
+        without_attributes: false,
+        synthesize: hr,
+    },
+    HintHandler {
+        local_names: &["table"],
+        attributes: &["border"],
+        without_attributes: false,
+        synthesize: table,
+    },
+    HintHandler {
+        local_names: &["img"],
+        attributes: &["border"],
+        without_attributes: false,
+        synthesize: img,
+    },
+    HintHandler {
+        local_names: &["iframe"],
+        attributes: &["frameborder"],
+        without_attributes: false,
+        synthesize: iframe,
+    },
+];
+
+#[allow(unsafe_code)]
+unsafe fn hr(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLHRElement>().unwrap();
+
+    // A rule with a color or without shading is drawn as a flat bar in its
+    // color instead of the default inset one.
+    //
+    // https://html.spec.whatwg.org/multipage/#the-hr-element-2
+    let flat_rule_source = {
+        let element = &*cx.element.unsafe_get();
+        if element.get_attr_for_layout(&ns!(), &local_name!("noshade")).is_some() {
+            Some("noshade")
+        } else if element.get_attr_for_layout(&ns!(), &local_name!("color")).is_some() {
+            Some("color")
+        } else {
+            None
+        }
+    };
+
+    if let Some(source) = flat_rule_source {
+        push_border_style(source, specified::BorderStyle::Solid, push_hint);
+    }
+
+    // The `size` of a flat rule is the width of the borders that make it up,
+    // while the one of a shaded rule is its height, borders included.
+    //
+    // https://html.spec.whatwg.org/multipage/#the-hr-element-2
+    match this.get_size() {
+        Some(size) if flat_rule_source.is_some() => {
+            let width_value = specified::BorderSideWidth::Length(specified::Length::from_px(size as f32 / 2.));
+            push_hint(Some("size"), PropertyDeclaration::BorderTopWidth(width_value.clone()));
+            push_hint(Some("size"), PropertyDeclaration::BorderRightWidth(width_value.clone()));
+            push_hint(Some("size"), PropertyDeclaration::BorderBottomWidth(width_value.clone()));
+            push_hint(Some("size"), PropertyDeclaration::BorderLeftWidth(width_value));
+        },
+        Some(1) => {
+            push_hint(
+                Some("size"),
+                PropertyDeclaration::BorderBottomWidth(
+                    specified::BorderSideWidth::Length(specified::Length::from_px(0.))));
+        },
+        Some(size) if size > 1 => {
+            push_hint(
+                Some("size"),
+                PropertyDeclaration::Height(specified::LengthOrPercentageOrAuto::Length(
+                    specified::NoCalcLength::Absolute(specified::AbsoluteLength::Px((size - 2) as CSSFloat)))));
+        },
+        _ => {},
+    }
+}
+
+#[allow(unsafe_code)]
+unsafe fn table(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLTableElement>().unwrap();
+    if let Some(border) = this.get_border() {
+        push_border_width(border, push_hint);
+    }
+}
+
+/// https://html.spec.whatwg.org/multipage/#images-3
+#[allow(unsafe_code)]
+unsafe fn img(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLImageElement>().unwrap();
+    if let Some(border) = this.get_border() {
+        push_border_width(border, push_hint);
+        // The border of an image is solid. This also replaces the default
+        // border of linked images, so that `<a><img border=0></a>` has none.
+        push_border_style("border", specified::BorderStyle::Solid, push_hint);
+    }
+}
+
+/// A frame border is the default inset one of the user agent stylesheet,
+/// which `frameborder` turns off or sets explicitly. Like any hint, this wins
+/// over the user agent stylesheet but loses to author style, so an author
+/// `border` on the frame applies whatever `frameborder` says.
+///
+/// https://html.spec.whatwg.org/multipage/#frames-and-framesets:attr-iframe-frameborder
+#[allow(unsafe_code)]
+unsafe fn iframe(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLIFrameElement>().unwrap();
+    if let Some(frame_border) = this.get_frame_border() {
+        let (style, width) = if frame_border {
+            (specified::BorderStyle::Inset, specified::BorderSideWidth::Length(specified::Length::from_px(2.)))
+        } else {
+            (specified::BorderStyle::None, specified::BorderSideWidth::Length(specified::Length::from_px(0.)))
+        };
+        push_border_style("frameborder", style, push_hint);
+        push_hint(Some("frameborder"), PropertyDeclaration::BorderTopWidth(width.clone()));
+        push_hint(Some("frameborder"), PropertyDeclaration::BorderRightWidth(width.clone()));
+        push_hint(Some("frameborder"), PropertyDeclaration::BorderBottomWidth(width.clone()));
+        push_hint(Some("frameborder"), PropertyDeclaration::BorderLeftWidth(width));
+    }
+}
+
+fn push_border_width(border: u32, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let width_value = specified::BorderSideWidth::Length(specified::Length::from_px(border as f32));
+    push_hint(
+        Some("border"),
+        PropertyDeclaration::BorderTopWidth(width_value.clone()));
+    push_hint(
+        Some("border"),
+        PropertyDeclaration::BorderLeftWidth(width_value.clone()));
+    push_hint(
+        Some("border"),
+        PropertyDeclaration::BorderBottomWidth(width_value.clone()));
+    push_hint(
+        Some("border"),
+        PropertyDeclaration::BorderRightWidth(width_value));
+}
+
+fn push_border_style(source: &str,
+                     style: specified::BorderStyle,
+                     push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_hint(Some(source), PropertyDeclaration::BorderTopStyle(style));
+    push_hint(Some(source), PropertyDeclaration::BorderRightStyle(style));
+    push_hint(Some(source), PropertyDeclaration::BorderBottomStyle(style));
+    push_hint(Some(source), PropertyDeclaration::BorderLeftStyle(style));
+}
@@ -0,0 +1,216 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The presentational hints for the colors and backgrounds of elements.
+
+use cssparser::RGBA;
+use dom::bindings::inheritance::Castable;
+use dom::document::LayoutDocumentHelpers;
+use dom::element::{LayoutElementHelpers, RawLayoutElementHelpers};
+use dom::htmlbodyelement::{HTMLBodyElement, HTMLBodyElementLayoutHelpers};
+use dom::htmlfontelement::{HTMLFontElement, HTMLFontElementLayoutHelpers};
+use dom::htmlhrelement::{HTMLHRElement, HTMLHRLayoutHelpers};
+use dom::htmltablecellelement::{HTMLTableCellElement, HTMLTableCellElementLayoutHelpers};
+use dom::htmltableelement::{HTMLTableElement, HTMLTableElementLayoutHelpers};
+use dom::htmltablerowelement::{HTMLTableRowElement, HTMLTableRowElementLayoutHelpers};
+use dom::htmltablesectionelement::{HTMLTableSectionElement, HTMLTableSectionElementLayoutHelpers};
+use selectors::matching::VisitedHandlingMode;
+use servo_url::ServoUrl;
+use style::attr::AttrValue;
+use style::element_state::ElementState;
+use style::properties::PropertyDeclaration;
+use style::properties::longhands::{self, background_image};
+use style::values::Either;
+use style::values::specified;
+use super::{HintContext, HintHandler};
+
+pub const HANDLERS: &'static [HintHandler] = &[
+    HintHandler {
+        local_names: &["body"],
+        attributes: &["bgcolor", "background", "text"],
+        without_attributes: false,
+        synthesize: body,
+    },
+    HintHandler {
+        local_names: &["table"],
+        attributes: &["bgcolor", "background"],
+        without_attributes: false,
+        synthesize: table,
+    },
+    HintHandler {
+        local_names: &["td", "th"],
+        attributes: &["bgcolor", "background"],
+        without_attributes: false,
+        synthesize: table_cell,
+    },
+    HintHandler {
+        local_names: &["tr"],
+        attributes: &["bgcolor", "background"],
+        without_attributes: false,
+        synthesize: table_row,
+    },
+    HintHandler {
+        local_names: &["tbody", "tfoot", "thead"],
+        attributes: &["bgcolor", "background"],
+        without_attributes: false,
+        synthesize: table_section,
+    },
+    HintHandler {
+        local_names: &["marquee"],
+        attributes: &["bgcolor"],
+        without_attributes: false,
+        synthesize: marquee,
+    },
+    HintHandler {
+        local_names: &["font"],
+        attributes: &["color"],
+        without_attributes: false,
+        synthesize: font,
+    },
+    HintHandler {
+        local_names: &["hr"],
+        attributes: &["color"],
+        without_attributes: false,
+        synthesize: hr,
+    },
+    HintHandler {
+        local_names: &["a", "area", "link"],
+        attributes: &["href"],
+        without_attributes: false,
+        synthesize: link,
+    },
+];
+
+#[allow(unsafe_code)]
+unsafe fn body(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLBodyElement>().unwrap();
+    // Layout propagates the computed background color of the body to the
+    // viewport, so this takes part in it like an author style would.
+    push_background_color(this.get_background_color(), push_hint);
+    push_background(this.get_background(), push_hint);
+    // https://html.spec.whatwg.org/multipage/#the-page:the-body-element-20
+    push_color(Some("text"), this.get_color(), push_hint);
+}
+
+#[allow(unsafe_code)]
+unsafe fn table(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLTableElement>().unwrap();
+    push_background_color(this.get_background_color(), push_hint);
+    push_background(this.get_background(), push_hint);
+}
+
+#[allow(unsafe_code)]
+unsafe fn table_cell(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLTableCellElement>().unwrap();
+    push_background_color(this.get_background_color(), push_hint);
+    push_background(this.get_background(), push_hint);
+}
+
+#[allow(unsafe_code)]
+unsafe fn table_row(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLTableRowElement>().unwrap();
+    push_background_color(this.get_background_color(), push_hint);
+    push_background(this.get_background(), push_hint);
+}
+
+#[allow(unsafe_code)]
+unsafe fn table_section(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLTableSectionElement>().unwrap();
+    push_background_color(this.get_background_color(), push_hint);
+    push_background(this.get_background(), push_hint);
+}
+
+/// https://html.spec.whatwg.org/multipage/#the-marquee-element-2
+#[allow(unsafe_code)]
+unsafe fn marquee(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let bgcolor = (*cx.element.unsafe_get())
+        .get_attr_for_layout(&ns!(), &local_name!("bgcolor"))
+        .and_then(AttrValue::as_color)
+        .cloned();
+    push_background_color(bgcolor, push_hint);
+}
+
+#[allow(unsafe_code)]
+unsafe fn font(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLFontElement>().unwrap();
+    push_color(Some("color"), this.get_color(), push_hint);
+}
+
+/// https://html.spec.whatwg.org/multipage/#the-hr-element-2:presentational-hints-5
+#[allow(unsafe_code)]
+unsafe fn hr(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLHRElement>().unwrap();
+    push_color(Some("color"), this.get_color(), push_hint);
+}
+
+/// The colors of links, which only apply to the elements that match `:link`,
+/// that is, `a`, `area` and `link` elements with an `href` attribute. Servo
+/// doesn't track visited links, so this is also `:any-link`.
+///
+/// These behave like `:link`, `:visited` and `:active` rules at the
+/// presentational hint level, so any author rule setting the color of the
+/// link wins over them.
+///
+/// The visited color only applies when styling the link as visited, and,
+/// like any other visited style, it must not affect anything but the color,
+/// so that pages can't find out which links were visited.
+///
+/// https://html.spec.whatwg.org/multipage/#selector-link
+/// https://html.spec.whatwg.org/multipage/#the-page:the-body-element-17
+#[allow(unsafe_code)]
+unsafe fn link(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let element = cx.element;
+    if (*element.unsafe_get()).get_attr_val_for_layout(&ns!(), &local_name!("href")).is_none() {
+        return;
+    }
+
+    let body = cx.document.body_for_layout();
+    let active_color = if element.get_state_for_layout().contains(ElementState::IN_ACTIVE_STATE) {
+        body.and_then(|body| body.get_active_link_color())
+    } else {
+        None
+    };
+    let color = active_color.or_else(|| match cx.visited_handling {
+        VisitedHandlingMode::RelevantLinkVisited => body.and_then(|body| body.get_visited_link_color()),
+        _ => body.and_then(|body| body.get_link_color()),
+    });
+    push_color(None, color, push_hint);
+}
+
+fn push_background_color(bgcolor: Option<RGBA>, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    if let Some(color) = bgcolor {
+        push_hint(
+            Some("bgcolor"),
+            PropertyDeclaration::BackgroundColor(color.into())
+        );
+    }
+}
+
+/// The URL is resolved against the base URL of the document when the
+/// attribute is set.
+///
+/// https://html.spec.whatwg.org/multipage/#tables-2
+fn push_background(background: Option<ServoUrl>, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    if let Some(url) = background {
+        push_hint(
+            Some("background"),
+            PropertyDeclaration::BackgroundImage(
+                background_image::SpecifiedValue(vec![
+                    Either::Second(specified::Image::for_cascade(url.into()))
+                ])));
+    }
+}
+
+fn push_color(source: Option<&str>,
+              color: Option<RGBA>,
+              push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    if let Some(color) = color {
+        push_hint(
+            source,
+            PropertyDeclaration::Color(
+                longhands::color::SpecifiedValue(color.into())
+            )
+        );
+    }
+}
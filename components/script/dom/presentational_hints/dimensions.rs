@@ -0,0 +1,317 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The presentational hints for the size and the margins of elements, that
+//! is, what dimension attributes like `width` or `hspace` map to.
+
+use dom::bindings::inheritance::Castable;
+use dom::htmlbodyelement::{HTMLBodyElement, HTMLBodyElementLayoutHelpers};
+use dom::htmlcanvaselement::{HTMLCanvasElement, LayoutHTMLCanvasElementHelpers};
+use dom::htmlhrelement::{HTMLHRElement, HTMLHRLayoutHelpers};
+use dom::htmliframeelement::{HTMLIFrameElement, HTMLIFrameElementLayoutMethods};
+use dom::htmlimageelement::{HTMLImageElement, LayoutHTMLImageElementHelpers};
+use dom::htmltableelement::{HTMLTableElement, HTMLTableElementLayoutHelpers};
+use dom::marquee::parse_marquee_direction;
+use style::attr::{AttrValue, LengthOrPercentageOrAuto};
+use style::properties::PropertyDeclaration;
+use style::values::{CSSFloat, computed, specified};
+use super::{HintContext, HintHandler, get_length_attribute, report_rejected_attribute};
+
+/// The height of a marquee whose contents move vertically, unless it has a
+/// `height` attribute.
+const DEFAULT_VERTICAL_MARQUEE_HEIGHT: CSSFloat = 200.;
+
+pub const HANDLERS: &'static [HintHandler] = &[
+    HintHandler {
+        local_names: &["iframe"],
+        attributes: &["width", "height"],
+        without_attributes: false,
+        synthesize: iframe,
+    },
+    HintHandler {
+        local_names: &["img"],
+        attributes: &["width", "height", "hspace", "vspace"],
+        without_attributes: false,
+        synthesize: img,
+    },
+    HintHandler {
+        local_names: &["table"],
+        attributes: &["width", "height"],
+        without_attributes: false,
+        synthesize: table,
+    },
+    HintHandler {
+        local_names: &["td", "th"],
+        attributes: &["width", "height"],
+        without_attributes: false,
+        synthesize: table_cell,
+    },
+    HintHandler {
+        local_names: &["tr"],
+        attributes: &["height"],
+        without_attributes: false,
+        synthesize: table_row,
+    },
+    HintHandler {
+        local_names: &["col", "colgroup"],
+        attributes: &["width"],
+        without_attributes: false,
+        synthesize: table_column,
+    },
+    HintHandler {
+        local_names: &["hr"],
+        attributes: &["width"],
+        without_attributes: false,
+        synthesize: hr,
+    },
+    HintHandler {
+        local_names: &["canvas"],
+        attributes: &["width", "height"],
+        without_attributes: false,
+        synthesize: canvas,
+    },
+    HintHandler {
+        local_names: &["object"],
+        attributes: &["width", "height", "hspace", "vspace"],
+        without_attributes: false,
+        synthesize: object,
+    },
+    HintHandler {
+        local_names: &["video"],
+        attributes: &["width", "height"],
+        without_attributes: false,
+        synthesize: video,
+    },
+    HintHandler {
+        local_names: &["embed"],
+        attributes: &["hspace", "vspace"],
+        without_attributes: false,
+        synthesize: embed,
+    },
+    HintHandler {
+        local_names: &["marquee"],
+        attributes: &["width", "height", "direction", "hspace", "vspace"],
+        without_attributes: false,
+        synthesize: marquee,
+    },
+    HintHandler {
+        local_names: &["body"],
+        attributes: &["topmargin", "rightmargin", "bottommargin", "leftmargin", "marginheight", "marginwidth"],
+        without_attributes: false,
+        synthesize: body,
+    },
+];
+
+#[allow(unsafe_code)]
+unsafe fn iframe(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLIFrameElement>().unwrap();
+    push_width(cx, this.get_width(), push_hint);
+    push_height(cx, this.get_height(), push_hint);
+}
+
+#[allow(unsafe_code)]
+unsafe fn img(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLImageElement>().unwrap();
+    push_width(cx, this.get_width(), push_hint);
+    push_height(cx, this.get_height(), push_hint);
+    push_space(this.get_hspace(), this.get_vspace(), push_hint);
+}
+
+/// Tables always grow to fit their content in other engines, so the `height`
+/// attribute of a table maps to `min-height` instead, which keeps that
+/// behavior regardless of how layout treats `height` on tables.
+#[allow(unsafe_code)]
+unsafe fn table(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLTableElement>().unwrap();
+    push_width(cx, this.get_width(), push_hint);
+
+    match this.get_height() {
+        LengthOrPercentageOrAuto::Auto => {
+            report_rejected_attribute(cx.element, cx.stylist, &local_name!("height"), is_rejected_dimension);
+        }
+        LengthOrPercentageOrAuto::Percentage(percentage) => {
+            push_hint(
+                Some("height"),
+                PropertyDeclaration::MinHeight(
+                    specified::LengthOrPercentage::Percentage(computed::Percentage(percentage))));
+        }
+        LengthOrPercentageOrAuto::Length(length) => {
+            push_hint(
+                Some("height"),
+                PropertyDeclaration::MinHeight(specified::LengthOrPercentage::Length(
+                    specified::NoCalcLength::Absolute(specified::AbsoluteLength::Px(length.to_f32_px())))));
+        }
+    }
+}
+
+/// Rows and cells already grow to fit their content, as `height` is a minimum
+/// for them.
+#[allow(unsafe_code)]
+unsafe fn table_cell(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_width(cx, get_length_attribute(cx.element, &local_name!("width")), push_hint);
+    push_height(cx, get_length_attribute(cx.element, &local_name!("height")), push_hint);
+}
+
+#[allow(unsafe_code)]
+unsafe fn table_row(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_height(cx, get_length_attribute(cx.element, &local_name!("height")), push_hint);
+}
+
+/// Layout reads the width of columns from the style of `col` elements, or of
+/// `colgroup` elements without any, like for author style.
+///
+/// https://html.spec.whatwg.org/multipage/#tables-2:attr-col-width
+#[allow(unsafe_code)]
+unsafe fn table_column(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_width(cx, get_length_attribute(cx.element, &local_name!("width")), push_hint);
+}
+
+/// https://html.spec.whatwg.org/multipage/#the-hr-element-2:attr-hr-width
+#[allow(unsafe_code)]
+unsafe fn hr(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLHRElement>().unwrap();
+    push_width(cx, this.get_width(), push_hint);
+}
+
+#[allow(unsafe_code)]
+unsafe fn canvas(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLCanvasElement>().unwrap();
+    push_width(cx, this.get_width(), push_hint);
+    push_height(cx, this.get_height(), push_hint);
+}
+
+/// https://html.spec.whatwg.org/multipage/#attributes-for-embedded-content-and-images
+#[allow(unsafe_code)]
+unsafe fn object(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_width(cx, get_length_attribute(cx.element, &local_name!("width")), push_hint);
+    push_height(cx, get_length_attribute(cx.element, &local_name!("height")), push_hint);
+    push_space(get_length_attribute(cx.element, &local_name!("hspace")),
+               get_length_attribute(cx.element, &local_name!("vspace")),
+               push_hint);
+}
+
+/// https://html.spec.whatwg.org/multipage/#attributes-for-embedded-content-and-images
+#[allow(unsafe_code)]
+unsafe fn video(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_width(cx, get_length_attribute(cx.element, &local_name!("width")), push_hint);
+    push_height(cx, get_length_attribute(cx.element, &local_name!("height")), push_hint);
+}
+
+/// https://html.spec.whatwg.org/multipage/#attributes-for-embedded-content-and-images
+#[allow(unsafe_code)]
+unsafe fn embed(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_space(get_length_attribute(cx.element, &local_name!("hspace")),
+               get_length_attribute(cx.element, &local_name!("vspace")),
+               push_hint);
+}
+
+/// https://html.spec.whatwg.org/multipage/#the-marquee-element-2:maps-to-the-dimension-property
+#[allow(unsafe_code)]
+unsafe fn marquee(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_width(cx, get_length_attribute(cx.element, &local_name!("width")), push_hint);
+
+    // A vertical marquee needs some room for its contents to move in.
+    let height = get_length_attribute(cx.element, &local_name!("height"));
+    if height == LengthOrPercentageOrAuto::Auto && parse_marquee_direction(cx.element).is_vertical() {
+        push_hint(
+            Some("direction"),
+            PropertyDeclaration::Height(specified::LengthOrPercentageOrAuto::Length(
+                specified::NoCalcLength::Absolute(specified::AbsoluteLength::Px(DEFAULT_VERTICAL_MARQUEE_HEIGHT)))));
+    }
+    push_height(cx, height, push_hint);
+
+    push_space(get_length_attribute(cx.element, &local_name!("hspace")),
+               get_length_attribute(cx.element, &local_name!("vspace")),
+               push_hint);
+}
+
+/// The specific attributes of each side win over the generic ones of its axis.
+///
+/// https://html.spec.whatwg.org/multipage/#the-page:attr-body-marginheight
+#[allow(unsafe_code)]
+unsafe fn body(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLBodyElement>().unwrap();
+    let pixels = |margin: u32| specified::LengthOrPercentageOrAuto::Length(
+        specified::NoCalcLength::Absolute(specified::AbsoluteLength::Px(margin as CSSFloat)));
+    if let Some((attribute, margin)) = this.get_margin("topmargin", "marginheight") {
+        push_hint(Some(attribute), PropertyDeclaration::MarginTop(pixels(margin)));
+    }
+    if let Some((attribute, margin)) = this.get_margin("rightmargin", "marginwidth") {
+        push_hint(Some(attribute), PropertyDeclaration::MarginRight(pixels(margin)));
+    }
+    if let Some((attribute, margin)) = this.get_margin("bottommargin", "marginheight") {
+        push_hint(Some(attribute), PropertyDeclaration::MarginBottom(pixels(margin)));
+    }
+    if let Some((attribute, margin)) = this.get_margin("leftmargin", "marginwidth") {
+        push_hint(Some(attribute), PropertyDeclaration::MarginLeft(pixels(margin)));
+    }
+}
+
+// FIXME(emilio): Use from_computed value here and below.
+#[allow(unsafe_code)]
+unsafe fn push_width(cx: &HintContext,
+                     width: LengthOrPercentageOrAuto,
+                     push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    if let Some(width_value) = dimension_value(width) {
+        push_hint(
+            Some("width"),
+            PropertyDeclaration::Width(width_value));
+    } else {
+        report_rejected_attribute(cx.element, cx.stylist, &local_name!("width"), is_rejected_dimension);
+    }
+}
+
+#[allow(unsafe_code)]
+unsafe fn push_height(cx: &HintContext,
+                      height: LengthOrPercentageOrAuto,
+                      push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    if let Some(height_value) = dimension_value(height) {
+        push_hint(
+            Some("height"),
+            PropertyDeclaration::Height(height_value));
+    } else {
+        report_rejected_attribute(cx.element, cx.stylist, &local_name!("height"), is_rejected_dimension);
+    }
+}
+
+/// Zero is a real length here, which resets the margins of author rules at
+/// the same level.
+fn push_space(hspace: LengthOrPercentageOrAuto,
+              vspace: LengthOrPercentageOrAuto,
+              push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    if let Some(margin) = dimension_value(hspace) {
+        push_hint(Some("hspace"), PropertyDeclaration::MarginLeft(margin.clone()));
+        push_hint(Some("hspace"), PropertyDeclaration::MarginRight(margin));
+    }
+
+    if let Some(margin) = dimension_value(vspace) {
+        push_hint(Some("vspace"), PropertyDeclaration::MarginTop(margin.clone()));
+        push_hint(Some("vspace"), PropertyDeclaration::MarginBottom(margin));
+    }
+}
+
+/// Whether `value` is a dimension attribute whose value isn't a valid length
+/// or percentage, like `width=abc`. Dimensions that parse to nothing keep
+/// their original string, which is what gets reported.
+pub fn is_rejected_dimension(value: &AttrValue) -> bool {
+    match *value {
+        AttrValue::Dimension(_, LengthOrPercentageOrAuto::Auto) => true,
+        _ => false,
+    }
+}
+
+/// The specified value of a dimension attribute, or `None` if it's `auto`,
+/// that is, missing or invalid.
+pub fn dimension_value(dimension: LengthOrPercentageOrAuto) -> Option<specified::LengthOrPercentageOrAuto> {
+    match dimension {
+        LengthOrPercentageOrAuto::Auto => None,
+        LengthOrPercentageOrAuto::Percentage(percentage) => {
+            Some(specified::LengthOrPercentageOrAuto::Percentage(computed::Percentage(percentage)))
+        },
+        LengthOrPercentageOrAuto::Length(length) => {
+            Some(specified::LengthOrPercentageOrAuto::Length(
+                specified::NoCalcLength::Absolute(specified::AbsoluteLength::Px(length.to_f32_px()))))
+        },
+    }
+}
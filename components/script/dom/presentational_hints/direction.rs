@@ -0,0 +1,55 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The presentational hints for the direction of text, which any HTML element
+//! may have.
+
+use dom::element::{LayoutElementHelpers, RawLayoutElementHelpers};
+use style::properties::PropertyDeclaration;
+use style::properties::longhands::{direction, unicode_bidi};
+use super::{HintContext, HintHandler};
+
+pub const HANDLERS: &'static [HintHandler] = &[
+    HintHandler {
+        local_names: &[],
+        attributes: &["dir"],
+        without_attributes: false,
+        synthesize: any_element,
+    },
+];
+
+/// An explicit direction isolates the element from the surrounding text, and
+/// `bdo` also overrides the direction of its own text.
+///
+/// FIXME: `dir=auto` still comes from the user agent stylesheet, since its
+/// direction depends on the text of the element.
+///
+/// https://html.spec.whatwg.org/multipage/#bidi-rendering
+#[allow(unsafe_code)]
+unsafe fn any_element(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let element = cx.element;
+    let dir = (*element.unsafe_get()).get_attr_val_for_layout(&ns!(), &local_name!("dir"));
+    if let Some(direction) = dir.and_then(explicit_direction) {
+        let unicode_bidi = if *element.local_name() == local_name!("bdo") {
+            unicode_bidi::SpecifiedValue::IsolateOverride
+        } else {
+            unicode_bidi::SpecifiedValue::Isolate
+        };
+        push_hint(
+            Some("dir"),
+            PropertyDeclaration::Direction(direction));
+        push_hint(
+            Some("dir"),
+            PropertyDeclaration::UnicodeBidi(unicode_bidi));
+    }
+}
+
+/// Maps the `dir` attribute to the direction it sets explicitly, if any.
+fn explicit_direction(dir: &str) -> Option<direction::SpecifiedValue> {
+    Some(match_ignore_ascii_case! { dir,
+        "ltr" => direction::SpecifiedValue::Ltr,
+        "rtl" => direction::SpecifiedValue::Rtl,
+        _ => return None,
+    })
+}
@@ -0,0 +1,192 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The presentational hints for the size and the text of form controls.
+
+use dom::bindings::inheritance::Castable;
+use dom::element::RawLayoutElementHelpers;
+use dom::htmlinputelement::{HTMLInputElement, InputType, LayoutHTMLInputElementHelpers};
+use dom::htmltextareaelement::{HTMLTextAreaElement, LayoutHTMLTextAreaElementHelpers};
+use servo_config::prefs::PREFS;
+use style::properties::PropertyDeclaration;
+use style::properties::longhands::{overflow_x, resize, white_space};
+use style::stylist::Stylist;
+use style::values::CSSFloat;
+use style::values::specified;
+use super::{HintContext, HintHandler};
+
+pub const HANDLERS: &'static [HintHandler] = &[
+    HintHandler {
+        local_names: &["input"],
+        attributes: &["size", "maxlength", "type"],
+        without_attributes: true,
+        synthesize: input,
+    },
+    HintHandler {
+        local_names: &["isindex", "keygen"],
+        attributes: &[],
+        without_attributes: true,
+        synthesize: obsolete_form_control,
+    },
+    HintHandler {
+        local_names: &["textarea"],
+        attributes: &["cols", "rows", "disabled", "readonly", "wrap"],
+        without_attributes: true,
+        synthesize: textarea,
+    },
+];
+
+#[allow(unsafe_code)]
+unsafe fn input(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLInputElement>().unwrap();
+
+    // Optionally size text fields without a `size` from their `maxlength`,
+    // like some legacy engines.
+    let size_from_maxlength = if PREFS.get("layout.legacy_attributes.maxlength_sizing.enabled")
+        .as_boolean().unwrap_or(false)
+    {
+        this.size_from_maxlength_for_layout()
+    } else {
+        None
+    };
+    let size = text_field_size(this.input_type_for_layout(),
+                               size_from_maxlength.unwrap_or_else(|| this.size_for_layout()));
+
+    if let Some(size) = size {
+        push_hint(
+            Some("size"),
+            PropertyDeclaration::Width(
+                specified::LengthOrPercentageOrAuto::Length(character_width(cx.stylist, size))));
+    }
+
+    // Graphical controls ignore `size`, and get a default box instead.
+    //
+    // FIXME: These should also get `appearance: auto`, so that authors can opt
+    // out of the native look with `appearance: none`, but Servo doesn't
+    // support the `appearance` property yet.
+    if let Some((width, height)) = graphical_input_default_size(this.input_type_for_layout()) {
+        push_hint(
+            Some("type"),
+            PropertyDeclaration::Width(specified::LengthOrPercentageOrAuto::Length(
+                specified::NoCalcLength::Absolute(specified::AbsoluteLength::Px(width)))));
+        push_hint(
+            Some("type"),
+            PropertyDeclaration::Height(specified::LengthOrPercentageOrAuto::Length(
+                specified::NoCalcLength::Absolute(specified::AbsoluteLength::Px(height)))));
+    }
+}
+
+/// The obsolete `keygen` and `isindex` elements are unknown elements now, but
+/// archived pages still use them as form controls, so render them as an
+/// empty box the size of a single line default text field rather than as
+/// nothing.
+///
+/// https://html.spec.whatwg.org/multipage/#non-conforming-features
+#[allow(unsafe_code)]
+unsafe fn obsolete_form_control(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_hint(
+        None,
+        PropertyDeclaration::Display(specified::Display::InlineBlock));
+    push_hint(
+        None,
+        PropertyDeclaration::Width(
+            specified::LengthOrPercentageOrAuto::Length(character_width(cx.stylist, 20))));
+    push_hint(
+        None,
+        PropertyDeclaration::Height(specified::LengthOrPercentageOrAuto::Length(
+            specified::NoCalcLength::FontRelative(specified::FontRelativeLength::Em(1.)))));
+}
+
+#[allow(unsafe_code)]
+unsafe fn textarea(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLTextAreaElement>().unwrap();
+
+    let cols = this.get_cols();
+    if cols != 0 {
+        // TODO(mttr) The character width uses the size math for <input type="text">, but
+        // the math for <textarea> is a little different since we need to take
+        // scrollbar size into consideration (but we don't have a scrollbar yet!)
+        //
+        // https://html.spec.whatwg.org/multipage/#textarea-effective-width
+        push_hint(
+            Some("cols"),
+            PropertyDeclaration::Width(
+                specified::LengthOrPercentageOrAuto::Length(character_width(cx.stylist, cols as i32))));
+    }
+
+    let rows = this.get_rows();
+    if rows != 0 {
+        // TODO(mttr) This should take scrollbar size into consideration.
+        //
+        // https://html.spec.whatwg.org/multipage/#textarea-effective-height
+        let value = specified::NoCalcLength::FontRelative(specified::FontRelativeLength::Em(rows as CSSFloat));
+        push_hint(
+            Some("rows"),
+            PropertyDeclaration::Height(specified::LengthOrPercentageOrAuto::Length(value)));
+    }
+
+    // Layout draws no resizer yet, so `resize` is only there behind a pref.
+    //
+    // https://html.spec.whatwg.org/multipage/#the-textarea-element-2
+    if PREFS.get("layout.resize.enabled").as_boolean().unwrap_or(false) && this.is_resizable() {
+        push_hint(None, PropertyDeclaration::Resize(resize::SpecifiedValue::Both));
+    }
+
+    // Text areas soft wrap their text unless `wrap` is in the off state, which
+    // isn't in the spec but is supported by every other engine. The `soft`
+    // and `hard` states keep the `pre-wrap` of the user agent style sheet.
+    //
+    // https://html.spec.whatwg.org/multipage/#attr-textarea-wrap
+    let wrap_off = (*cx.element.unsafe_get())
+        .get_attr_val_for_layout(&ns!(), &local_name!("wrap"))
+        .map_or(false, |wrap| wrap.eq_ignore_ascii_case("off"));
+
+    if wrap_off {
+        push_hint(
+            Some("wrap"),
+            PropertyDeclaration::WhiteSpace(white_space::SpecifiedValue::Pre));
+        push_hint(
+            Some("wrap"),
+            PropertyDeclaration::OverflowX(overflow_x::SpecifiedValue::Auto));
+    }
+}
+
+/// The width of `size` characters, for the controls that are sized in
+/// characters rather than in pixels.
+pub fn character_width(stylist: &Stylist, size: i32) -> specified::NoCalcLength {
+    if stylist.use_ch_unit() {
+        specified::NoCalcLength::FontRelative(specified::FontRelativeLength::Ch(size as CSSFloat))
+    } else {
+        specified::NoCalcLength::ServoCharacterWidth(specified::CharacterWidth(size))
+    }
+}
+
+/// The number of characters that a text field of `input_type` with the given
+/// `size` is wide, or `None` if the control isn't a text field or the size is
+/// zero.
+pub fn text_field_size(input_type: InputType, size: u32) -> Option<i32> {
+    match input_type {
+        // Not text entry widget
+        InputType::Hidden | InputType::Date | InputType::Month | InputType::Week |
+        InputType::Time | InputType::DatetimeLocal | InputType::Number | InputType::Range |
+        InputType::Color | InputType::Checkbox | InputType::Radio | InputType::File |
+        InputType::Submit | InputType::Image | InputType::Reset | InputType::Button => None,
+        // Others
+        _ => match size {
+            0 => None,
+            s => Some(s as i32),
+        },
+    }
+}
+
+/// The default width and height, in CSS pixels, of the `input` types that are
+/// rendered as graphical controls rather than as text fields.
+fn graphical_input_default_size(input_type: InputType) -> Option<(CSSFloat, CSSFloat)> {
+    match input_type {
+        InputType::Checkbox | InputType::Radio => Some((13., 13.)),
+        InputType::Color => Some((50., 27.)),
+        InputType::Range => Some((129., 16.)),
+        _ => None,
+    }
+}
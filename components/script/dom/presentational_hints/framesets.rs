@@ -0,0 +1,64 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The presentational hints for the frames of framesets.
+
+use dom::bindings::inheritance::Castable;
+use dom::bindings::root::LayoutDom;
+use dom::htmlframeelement::HTMLFrameElement;
+use dom::htmlframesetelement::{HTMLFrameSetElement, HTMLFrameSetElementLayoutHelpers};
+use dom::node::{LayoutNodeHelpers, Node};
+use style::properties::PropertyDeclaration;
+use style::values::specified;
+use super::{HintContext, HintHandler};
+
+pub const HANDLERS: &'static [HintHandler] = &[
+    HintHandler {
+        local_names: &["frame", "frameset"],
+        attributes: &[],
+        without_attributes: true,
+        synthesize: frame,
+    },
+];
+
+/// A frameset lays out its `frame` and `frameset` children in order, one per
+/// cell of the grid given by its `rows` and `cols`, and the children left once
+/// every cell is used aren't rendered. Each missing attribute counts as a
+/// single track.
+///
+/// FIXME: Servo doesn't lay out the cells of framesets yet, so this is the
+/// only effect their `rows` and `cols` have.
+///
+/// https://html.spec.whatwg.org/multipage/#frames-and-framesets
+#[allow(unsafe_code)]
+unsafe fn frame(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let node = cx.element.upcast::<Node>();
+    let frameset = match node.parent_node_ref().and_then(|parent| parent.downcast::<HTMLFrameSetElement>()) {
+        Some(frameset) => frameset,
+        None => return,
+    };
+    let cells = frameset.get_rows().map_or(1, |rows| rows.len()) *
+        frameset.get_cols().map_or(1, |cols| cols.len());
+
+    let mut index = 0;
+    let mut sibling = node.prev_sibling_ref();
+    while let Some(previous) = sibling {
+        if index >= cells {
+            break;
+        }
+        if is_frame(previous) {
+            index += 1;
+        }
+        sibling = previous.prev_sibling_ref();
+    }
+
+    if index >= cells {
+        push_hint(None, PropertyDeclaration::Display(specified::Display::None));
+    }
+}
+
+/// Whether `node` takes a cell of the grid of its frameset.
+fn is_frame(node: LayoutDom<Node>) -> bool {
+    node.downcast::<HTMLFrameElement>().is_some() || node.downcast::<HTMLFrameSetElement>().is_some()
+}
@@ -0,0 +1,178 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The presentational hints for lists and their markers.
+
+use dom::bindings::inheritance::Castable;
+use dom::bindings::root::LayoutDom;
+use dom::element::{Element, LayoutElementHelpers, RawLayoutElementHelpers};
+use dom::htmldetailselement::HTMLDetailsElement;
+use dom::node::{LayoutNodeHelpers, Node};
+use style::properties::PropertyDeclaration;
+use style::properties::longhands::list_style_type;
+use style::values::specified;
+use super::{HintContext, HintHandler};
+
+pub const HANDLERS: &'static [HintHandler] = &[
+    HintHandler {
+        local_names: &["dd"],
+        attributes: &[],
+        without_attributes: true,
+        synthesize: dd,
+    },
+    HintHandler {
+        local_names: &["summary"],
+        attributes: &[],
+        without_attributes: true,
+        synthesize: summary,
+    },
+    HintHandler {
+        local_names: &["li"],
+        attributes: &["type"],
+        without_attributes: false,
+        synthesize: li,
+    },
+    HintHandler {
+        local_names: &["ol"],
+        attributes: &["type"],
+        without_attributes: false,
+        synthesize: ol,
+    },
+    HintHandler {
+        local_names: &["ul"],
+        attributes: &["type"],
+        without_attributes: false,
+        synthesize: ul,
+    },
+];
+
+/// The default indentation of definition list descriptions goes through the
+/// presentational hints rather than the user-agent stylesheet.
+///
+/// https://html.spec.whatwg.org/multipage/#lists
+#[allow(unsafe_code)]
+unsafe fn dd(_cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    // FIXME: Use margin-inline-start when supported.
+    push_hint(
+        None,
+        PropertyDeclaration::MarginLeft(specified::LengthOrPercentageOrAuto::Length(
+            specified::NoCalcLength::Absolute(specified::AbsoluteLength::Px(40.)))
+        ));
+}
+
+/// The disclosure marker of a details element is drawn by its summary, and
+/// reflects whether the details element is open.
+///
+/// https://html.spec.whatwg.org/multipage/#the-details-and-summary-elements
+#[allow(unsafe_code)]
+unsafe fn summary(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    if let Some(open) = details_summary_open_state(cx.element) {
+        let marker = if open {
+            list_style_type::SpecifiedValue::DisclosureOpen
+        } else {
+            list_style_type::SpecifiedValue::DisclosureClosed
+        };
+        push_hint(
+            None,
+            PropertyDeclaration::Display(specified::Display::ListItem));
+        push_hint(
+            None,
+            PropertyDeclaration::ListStyleType(marker));
+    }
+}
+
+#[allow(unsafe_code)]
+unsafe fn li(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    // The `value` attribute isn't a hint: layout reads the ordinal value of
+    // the list item itself, see `ThreadSafeLayoutNode::get_ordinal_value`.
+    let list_type = list_type(cx.element)
+        .and_then(|list_type| ordered_list_style_type(list_type).or_else(|| unordered_list_style_type(list_type)));
+    push_list_style_type(list_type, push_hint);
+}
+
+#[allow(unsafe_code)]
+unsafe fn ol(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_list_style_type(list_type(cx.element).and_then(ordered_list_style_type), push_hint);
+}
+
+#[allow(unsafe_code)]
+unsafe fn ul(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_list_style_type(list_type(cx.element).and_then(unordered_list_style_type), push_hint);
+}
+
+/// The `type` attribute of a list or a list item.
+#[allow(unsafe_code)]
+unsafe fn list_type<'a>(element: &'a LayoutDom<Element>) -> Option<&'a str> {
+    (*element.unsafe_get()).get_attr_val_for_layout(&ns!(), &local_name!("type"))
+}
+
+/// The marker style of a list is inherited by its items, unless they have a
+/// `type` of their own. Ordered list types are case-sensitive, since `a` and
+/// `A` differ, but unordered ones are not.
+///
+/// https://html.spec.whatwg.org/multipage/#lists
+fn push_list_style_type(list_type: Option<list_style_type::SpecifiedValue>,
+                        push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    if let Some(list_style_type) = list_type {
+        push_hint(
+            Some("type"),
+            PropertyDeclaration::ListStyleType(list_style_type));
+    }
+}
+
+/// If the `summary` element `element` is the summary of a details element,
+/// that is, its first `summary` child, returns whether that details element
+/// is open.
+///
+/// https://html.spec.whatwg.org/multipage/#the-details-element:the-summary-element
+#[allow(unsafe_code)]
+unsafe fn details_summary_open_state(element: &LayoutDom<Element>) -> Option<bool> {
+    unsafe fn is_summary(element: &LayoutDom<Element>) -> bool {
+        element.is_html_element() && *element.local_name() == local_name!("summary")
+    }
+
+    let node = element.upcast::<Node>();
+    let parent = node.parent_node_ref();
+    let details = match parent.and_then(|parent| parent.downcast::<HTMLDetailsElement>()) {
+        Some(details) => details,
+        None => return None,
+    };
+
+    let mut sibling = node.prev_sibling_ref();
+    while let Some(current) = sibling {
+        if current.downcast::<Element>().map_or(false, |element| is_summary(&element)) {
+            return None;
+        }
+        sibling = current.prev_sibling_ref();
+    }
+
+    Some((*details.upcast::<Element>().unsafe_get())
+        .get_attr_for_layout(&ns!(), &local_name!("open"))
+        .is_some())
+}
+
+/// Maps the `type` attribute of ordered lists and list items to a marker
+/// style. The values are case-sensitive.
+fn ordered_list_style_type(list_type: &str) -> Option<list_style_type::SpecifiedValue> {
+    Some(match list_type {
+        "1" => list_style_type::SpecifiedValue::Decimal,
+        "a" => list_style_type::SpecifiedValue::LowerAlpha,
+        "A" => list_style_type::SpecifiedValue::UpperAlpha,
+        "i" => list_style_type::SpecifiedValue::LowerRoman,
+        "I" => list_style_type::SpecifiedValue::UpperRoman,
+        _ => return None,
+    })
+}
+
+/// Maps the `type` attribute of unordered lists and list items to a marker
+/// style.
+fn unordered_list_style_type(list_type: &str) -> Option<list_style_type::SpecifiedValue> {
+    Some(match_ignore_ascii_case! { list_type,
+        "none" => list_style_type::SpecifiedValue::None,
+        "disc" => list_style_type::SpecifiedValue::Disc,
+        "circle" => list_style_type::SpecifiedValue::Circle,
+        "square" => list_style_type::SpecifiedValue::Square,
+        _ => return None,
+    })
+}
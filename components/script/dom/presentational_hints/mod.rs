@@ -0,0 +1,466 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Synthesis of the presentational hints for legacy HTML attributes, that is,
+//! the declarations that attributes like `bgcolor` or `width` map to.
+//!
+//! https://html.spec.whatwg.org/multipage/#presentational-hints
+
+use dom::bindings::inheritance::Castable;
+use dom::bindings::root::LayoutDom;
+use dom::document::{Document, LayoutDocumentHelpers};
+use dom::element::{Element, LayoutElementHelpers, RawLayoutElementHelpers};
+use dom::node::{LayoutNodeHelpers, Node, NodeFlags};
+use html5ever::{LocalName, Namespace};
+use log::Level;
+use selectors::matching::VisitedHandlingMode;
+use selectors::sink::Push;
+use servo_arc::Arc;
+use smallvec::SmallVec;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use style::applicable_declarations::ApplicableDeclarationBlock;
+use style::attr::{AttrValue, LengthOrPercentageOrAuto};
+use style::properties::{DeclarationSource, Importance, PropertyDeclaration};
+use style::properties::{PropertyDeclarationBlock, PropertyDeclarationId};
+use style::rule_tree::CascadeLevel;
+use style::shared_lock::SharedRwLock;
+use style::stylist::{PresentationalHintElement, Stylist};
+
+pub use self::alignment::{embedded_content_alignment, line_break_clear};
+pub use self::dimensions::{dimension_value, is_rejected_dimension};
+pub use self::forms::{character_width, text_field_size};
+
+mod alignment;
+mod borders;
+mod colors;
+mod dimensions;
+mod direction;
+mod forms;
+mod framesets;
+mod lists;
+mod scrolling;
+mod tables;
+mod text;
+
+/// The maximum number of presentational hints synthesized for an element.
+///
+/// Each hint comes from a fixed place in the module of its category, so no
+/// element gets anywhere near this, but it bounds the declaration blocks
+/// allocated for an element should a future hint depend on the number of
+/// attributes.
+const MAX_HINTS_PER_ELEMENT: usize = 64;
+
+/// Pushes the presentational hints for the legacy attributes of `element` to
+/// `hints`.
+///
+/// When styling a link as visited, per `visited_handling`, only the hints
+/// allowed for visited links are pushed.
+#[allow(unsafe_code)]
+pub unsafe fn synthesize_presentational_hints_for_legacy_attributes<V>(element: &LayoutDom<Element>,
+                                                                       stylist: &Stylist,
+                                                                       visited_handling: VisitedHandlingMode,
+                                                                       hints: &mut V)
+    where V: Push<ApplicableDeclarationBlock>
+{
+    let document = element.upcast::<Node>().owner_doc_for_layout();
+    let mut declarations = HintDeclarations::new();
+    synthesize(element, stylist, visited_handling, &mut |declaration| declarations.push(declaration));
+    if !declarations.is_empty() {
+        hints.push(from_declarations(document.style_shared_lock(), declarations))
+    }
+}
+
+/// Returns a canonical serialization of the presentational hints of
+/// `element`, which is stable enough to compare the hints of two elements
+/// byte for byte. See `serialize_hints`.
+#[allow(unsafe_code)]
+pub unsafe fn serialize_presentational_hints(element: &LayoutDom<Element>, stylist: &Stylist) -> String {
+    let mut declarations = vec![];
+    synthesize(element, stylist, VisitedHandlingMode::AllLinksUnvisited, &mut |declaration| declarations.push(declaration));
+    serialize_hints(&declarations)
+}
+
+/// Returns the presentational hints of `element` as the text of a CSS
+/// declaration block, for developer tools to show next to the rules from
+/// style sheets. See `hints_to_css_string`.
+#[allow(unsafe_code)]
+pub unsafe fn presentational_hints_to_css_string(element: &LayoutDom<Element>, stylist: &Stylist) -> String {
+    let mut declarations = vec![];
+    synthesize(element, stylist, VisitedHandlingMode::AllLinksUnvisited, &mut |declaration| {
+        declarations.push(declaration)
+    });
+    hints_to_css_string(&declarations)
+}
+
+/// The declarations of the presentational hints of an element, in the order
+/// they were synthesized. Most elements have a handful at most.
+type HintDeclarations = SmallVec<[PropertyDeclaration; 4]>;
+
+/// Returns the single declaration block for all the presentational hints of
+/// an element, so that the rule tree gets one node for them rather than one
+/// per declaration.
+///
+/// The order of `declarations` is kept, so the last declaration of a property
+/// wins, as if each of them had a block of its own.
+#[inline]
+fn from_declarations(shared_lock: &SharedRwLock, declarations: HintDeclarations) -> ApplicableDeclarationBlock {
+    // Pushing replaces any previous declaration of the same property, which
+    // keeps the last one like the cascade would.
+    let mut block = PropertyDeclarationBlock::new();
+    for declaration in declarations {
+        block.push(declaration, Importance::Normal, DeclarationSource::CssOm);
+    }
+    ApplicableDeclarationBlock::from_declarations(Arc::new(shared_lock.wrap(block)), CascadeLevel::PresHints)
+}
+
+/// A handler of presentational hints, which pushes the hints of one category
+/// for the HTML elements with some local names.
+///
+/// Each module of a category lists its handlers in a `HANDLERS` table, along
+/// with the elements and attributes they look at, and that is the only place
+/// that says which handlers run for which elements.
+pub struct HintHandler {
+    /// The local names of the elements this handler is for, or none if it is
+    /// for every HTML element.
+    local_names: &'static [&'static str],
+    /// The attributes of the element that the hints of this handler come
+    /// from.
+    attributes: &'static [&'static str],
+    /// Whether this handler may push hints for an element that has none of
+    /// `attributes`, because they come from its type, from the attributes of
+    /// other elements, or from the document.
+    without_attributes: bool,
+    /// Pushes the hints of this handler for `cx.element` to `push_hint`.
+    synthesize: unsafe fn(&HintContext, &mut FnMut(Option<&str>, PropertyDeclaration)),
+}
+
+impl HintHandler {
+    /// The attributes that the hints of this handler come from.
+    pub fn attributes(&self) -> &'static [&'static str] {
+        self.attributes
+    }
+
+    /// Whether this handler is for the HTML elements with `local_name`.
+    fn applies_to(&self, local_name: &str) -> bool {
+        self.local_names.is_empty() || self.local_names.contains(&local_name)
+    }
+}
+
+/// The handlers of each category, in the order their hints are pushed in.
+///
+/// Later hints override earlier ones for the same property, so no two
+/// categories may set the same property on the same element, and a category
+/// keeps the hints that override each other in the order they apply.
+const HANDLERS: &'static [&'static [HintHandler]] = &[
+    colors::HANDLERS,
+    text::HANDLERS,
+    tables::HANDLERS,
+    forms::HANDLERS,
+    framesets::HANDLERS,
+    dimensions::HANDLERS,
+    lists::HANDLERS,
+    alignment::HANDLERS,
+    borders::HANDLERS,
+    scrolling::HANDLERS,
+    direction::HANDLERS,
+];
+
+/// The handlers of presentational hints of the HTML elements with each local
+/// name, built once from the `HANDLERS` of every category.
+struct HintHandlerTable {
+    by_local_name: HashMap<LocalName, Vec<&'static HintHandler>>,
+    /// The handlers of the HTML elements with any other local name.
+    others: Vec<&'static HintHandler>,
+}
+
+impl HintHandlerTable {
+    fn new() -> HintHandlerTable {
+        let handlers = || HANDLERS.iter().flat_map(|&handlers| handlers.iter());
+        let mut by_local_name = HashMap::new();
+        for handler in handlers() {
+            for &local_name in handler.local_names {
+                by_local_name.entry(LocalName::from(local_name)).or_insert_with(|| {
+                    handlers().filter(|other| other.applies_to(local_name)).collect()
+                });
+            }
+        }
+        HintHandlerTable {
+            by_local_name: by_local_name,
+            others: handlers().filter(|handler| handler.local_names.is_empty()).collect(),
+        }
+    }
+
+    fn get(&self, local_name: &LocalName) -> &[&'static HintHandler] {
+        match self.by_local_name.get(local_name) {
+            Some(handlers) => handlers,
+            None => &self.others,
+        }
+    }
+}
+
+lazy_static! {
+    static ref HINT_HANDLERS: HintHandlerTable = HintHandlerTable::new();
+}
+
+/// The handlers of the presentational hints of an HTML element with
+/// `local_name`, in the order their hints are pushed in.
+pub fn hint_handlers(local_name: &LocalName) -> &'static [&'static HintHandler] {
+    HINT_HANDLERS.get(local_name)
+}
+
+/// Whether the `attribute` of an HTML element with `local_name` may map to
+/// one of its built-in presentational hints, that is, whether one of its
+/// handlers lists it, so that changing it needs to restyle the element.
+///
+/// Attributes that map to hints of other elements, like the `cellpadding` of
+/// a table, restyle those elements when they change instead.
+pub fn attribute_affects_presentational_hints(local_name: &LocalName, attribute: &LocalName) -> bool {
+    hint_handlers(local_name).iter().any(|handler| handler.attributes.contains(&&**attribute))
+}
+
+/// The element whose presentational hints are synthesized, along with what
+/// its hints depend on besides its own attributes.
+pub struct HintContext<'a> {
+    element: &'a LayoutDom<Element>,
+    stylist: &'a Stylist,
+    document: LayoutDom<Document>,
+    visited_handling: VisitedHandlingMode,
+}
+
+/// Emits the presentational hints of `element` to `emit`.
+///
+/// The hints only depend on the interface and local name of `element`, never
+/// on its `is` value, so a customized built-in element gets the hints of the
+/// element it extends and an autonomous custom element gets none.
+#[allow(unsafe_code)]
+unsafe fn synthesize(element: &LayoutDom<Element>,
+                     stylist: &Stylist,
+                     visited_handling: VisitedHandlingMode,
+                     emit: &mut FnMut(PropertyDeclaration))
+{
+    let handlers = if element.is_html_element() {
+        hint_handlers(element.local_name())
+    } else {
+        &[]
+    };
+
+    // Most elements, like `div` or `span` without attributes, have no hints at
+    // all, so don't look any further at them.
+    if !element.upcast::<Node>().get_flag(NodeFlags::HAS_PRESENTATIONAL_HINT_ATTRIBUTES) &&
+        !handlers.iter().any(|handler| handler.without_attributes) &&
+        stylist.presentational_hint_synthesizers().is_empty()
+    {
+        return;
+    }
+
+    // Pushes the hint for `declaration`, which comes from `attribute` if any.
+    //
+    // Each hint is logged with its source when the `presentational_hints` log
+    // target is enabled at the debug level, which helps to find out why an
+    // element got some unexpected style.
+    //
+    // Hints beyond `MAX_HINTS_PER_ELEMENT` are dropped.
+    let mut hint_count = 0;
+    let mut push_hint = |attribute: Option<&str>, declaration: PropertyDeclaration| {
+        if hint_count == MAX_HINTS_PER_ELEMENT {
+            warn!("Dropping presentational hints beyond the first {} of a {} element",
+                  MAX_HINTS_PER_ELEMENT, element.local_name());
+            return;
+        }
+        hint_count += 1;
+
+        if log_enabled!(target: "presentational_hints", Level::Debug) {
+            let attribute = attribute.map(|name| {
+                let value = (*element.unsafe_get())
+                    .get_attr_val_for_layout(&ns!(), &LocalName::from(name))
+                    .unwrap_or("");
+                (name, value)
+            });
+            debug!(target: "presentational_hints", "{}",
+                   describe_hint(element.local_name(), attribute, &declaration));
+        }
+        emit(declaration);
+    };
+
+    // NB: Hints are pushed in a fixed order rather than in the order the
+    // attributes appear in the source, so that elements with the same
+    // attributes always produce the same declarations in the same order,
+    // regardless of how the attributes were written.
+    if !handlers.is_empty() {
+        let cx = HintContext {
+            element: element,
+            stylist: stylist,
+            document: element.upcast::<Node>().owner_doc_for_layout(),
+            visited_handling: visited_handling,
+        };
+        for handler in handlers {
+            (handler.synthesize)(&cx, &mut |attribute: Option<&str>, declaration: PropertyDeclaration| {
+                debug_assert!(attribute.map_or(true, |name| handler.attributes.contains(&name)),
+                              "A handler of the hints of {} doesn't list {:?} among its attributes",
+                              element.local_name(), attribute);
+                push_hint(attribute, declaration)
+            });
+        }
+    }
+
+    // The synthesizers registered by the embedder go last, so that they can
+    // override the built-in mappings.
+    for synthesizer in stylist.presentational_hint_synthesizers() {
+        synthesizer.synthesize(&HintElement(element), &mut |attribute, declaration| {
+            debug_assert!(synthesizer.affects_attribute(element.local_name(), attribute),
+                          "A presentational hint synthesizer doesn't know that {} of {} maps to hints",
+                          attribute, element.local_name());
+            push_hint(Some(&**attribute), declaration)
+        });
+    }
+}
+
+/// The attributes of an element, as seen by the presentational hint
+/// synthesizers registered by the embedder.
+struct HintElement<'a>(&'a LayoutDom<Element>);
+
+impl<'a> PresentationalHintElement for HintElement<'a> {
+    fn local_name(&self) -> &LocalName {
+        self.0.local_name()
+    }
+
+    fn namespace(&self) -> &Namespace {
+        self.0.namespace()
+    }
+
+    #[allow(unsafe_code)]
+    fn get_attr(&self, name: &LocalName) -> Option<&str> {
+        unsafe { (*self.0.unsafe_get()).get_attr_val_for_layout(&ns!(), name) }
+    }
+}
+
+/// Describes a presentational hint for logging, as the local name of the
+/// element, followed by the attribute the hint comes from if any, and the
+/// resulting declaration, like `td width="50" -> width: 50px`.
+pub fn describe_hint(local_name: &str,
+                     attribute: Option<(&str, &str)>,
+                     declaration: &PropertyDeclaration)
+                     -> String {
+    match attribute {
+        Some((name, value)) => format!("{} {}={:?} -> {:?}", local_name, name, value, declaration),
+        None => format!("{} -> {:?}", local_name, declaration),
+    }
+}
+
+/// Serializes the effective `declarations` of presentational hints, sorted by
+/// property, as a `;`-separated list of `property: value` pairs.
+///
+/// Only the last declaration of each property is serialized, since it
+/// overrides the previous ones, so declarations that don't change the style
+/// of the element don't change the serialization either.
+///
+/// Each pair is the name of the property and the CSS serialization of its
+/// value, so that it doesn't depend on the `Debug` output of declarations.
+pub fn serialize_hints(declarations: &[PropertyDeclaration]) -> String {
+    let mut effective: Vec<&PropertyDeclaration> = vec![];
+    for declaration in declarations.iter().rev() {
+        if !effective.iter().any(|other| other.id() == declaration.id()) {
+            effective.push(declaration);
+        }
+    }
+
+    let mut serialized: Vec<String> = effective.iter().map(|declaration| {
+        let mut pair = String::from(declaration.id().name());
+        pair.push_str(": ");
+        declaration.to_css(&mut pair).unwrap();
+        pair
+    }).collect();
+    serialized.sort();
+    serialized.join("; ")
+}
+
+/// Serializes `declarations` as the text of a CSS declaration block, like
+/// `width: 25%; background-color: rgb(255, 0, 0);`, which is empty if there
+/// are no declarations.
+///
+/// The block is built the way the hints cascade, so only the last
+/// declaration of each property is kept, and it is serialized like the style
+/// attribute of an element, shorthands included.
+pub fn hints_to_css_string(declarations: &[PropertyDeclaration]) -> String {
+    let mut block = PropertyDeclarationBlock::new();
+    for declaration in declarations {
+        block.push(declaration.clone(), Importance::Normal, DeclarationSource::CssOm);
+    }
+    let mut css = String::new();
+    block.to_css(&mut css).unwrap();
+    css
+}
+
+/// Accumulates the declarations of presentational hints, in order, into a
+/// digest.
+pub struct HintsDigest(DefaultHasher);
+
+impl HintsDigest {
+    pub fn new() -> HintsDigest {
+        HintsDigest(DefaultHasher::new())
+    }
+
+    /// Adds `declaration` to the digest.
+    ///
+    /// The property is hashed as its id, and the value as its CSS
+    /// serialization, which is what makes two equal declarations hash the
+    /// same. The value is serialized into a buffer reused across calls on the
+    /// same thread, so this doesn't allocate once the buffer is big enough.
+    pub fn push(&mut self, declaration: &PropertyDeclaration) {
+        thread_local!(static VALUE_BUFFER: RefCell<String> = RefCell::new(String::new()));
+
+        match declaration.id() {
+            PropertyDeclarationId::Longhand(id) => id.hash(&mut self.0),
+            PropertyDeclarationId::Custom(name) => name.hash(&mut self.0),
+        }
+        VALUE_BUFFER.with(|buffer| {
+            let mut buffer = buffer.borrow_mut();
+            buffer.clear();
+            let _ = declaration.to_css(&mut *buffer);
+            self.0.write(buffer.as_bytes());
+        });
+        // Separate the declarations from each other.
+        self.0.write_u8(0);
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+}
+
+/// Returns the `name` attribute of `element`, which must be parsed as a
+/// dimension, so that callers can tell lengths from percentages before
+/// deciding what to emit.
+#[allow(unsafe_code)]
+unsafe fn get_length_attribute(element: &LayoutDom<Element>, name: &LocalName) -> LengthOrPercentageOrAuto {
+    (*element.unsafe_get())
+        .get_attr_for_layout(&ns!(), name)
+        .map(AttrValue::as_dimension)
+        .cloned()
+        .unwrap_or(LengthOrPercentageOrAuto::Auto)
+}
+
+/// Reports the value of the `name` attribute of `element` to the attribute
+/// error reporter of `stylist`, if there's one, when `is_rejected` says that
+/// the attribute couldn't be parsed into a hint.
+#[allow(unsafe_code)]
+unsafe fn report_rejected_attribute<F>(element: &LayoutDom<Element>,
+                                       stylist: &Stylist,
+                                       name: &LocalName,
+                                       is_rejected: F)
+    where F: FnOnce(&AttrValue) -> bool,
+{
+    let reporter = match stylist.attribute_error_reporter() {
+        Some(reporter) => reporter,
+        None => return,
+    };
+    if let Some(value) = (*element.unsafe_get()).get_attr_for_layout(&ns!(), name) {
+        if is_rejected(value) {
+            reporter.report_invalid_attribute(element.local_name(), name, &**value);
+        }
+    }
+}
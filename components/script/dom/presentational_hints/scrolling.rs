@@ -0,0 +1,61 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The presentational hints for the scrolling of bodies and frames.
+
+use dom::bindings::inheritance::Castable;
+use dom::htmlbodyelement::{HTMLBodyElement, HTMLBodyElementLayoutHelpers};
+use dom::htmliframeelement::{HTMLIFrameElement, HTMLIFrameElementLayoutMethods};
+use style::properties::PropertyDeclaration;
+use style::properties::longhands::{overflow_x, overflow_y};
+use super::{HintContext, HintHandler};
+
+pub const HANDLERS: &'static [HintHandler] = &[
+    HintHandler {
+        local_names: &["body"],
+        attributes: &["scroll"],
+        without_attributes: false,
+        synthesize: body,
+    },
+    HintHandler {
+        local_names: &["iframe"],
+        attributes: &["scrolling"],
+        without_attributes: false,
+        synthesize: iframe,
+    },
+];
+
+/// Only the body-level overflow is synthesized here. Propagating it to the
+/// viewport, which is what actually hides the document scrollbars, is left to
+/// layout like for author-specified `overflow` on the body.
+///
+/// https://drafts.csswg.org/css-overflow/#overflow-propagation
+#[allow(unsafe_code)]
+unsafe fn body(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    if cx.element.downcast::<HTMLBodyElement>().unwrap().get_scrolling_disabled() {
+        push_overflow_hidden("scroll", push_hint);
+    }
+}
+
+/// FIXME: `scrolling=no` should also hide the scrollbars of the nested
+/// document, and `marginwidth` and `marginheight` should set the margins of
+/// its body, but the nested document may live in another script thread and
+/// its style can't depend on the frame yet.
+///
+/// https://html.spec.whatwg.org/multipage/#the-page:attr-iframe-marginheight
+#[allow(unsafe_code)]
+unsafe fn iframe(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    if cx.element.downcast::<HTMLIFrameElement>().unwrap().get_scrolling_disabled() {
+        push_overflow_hidden("scrolling", push_hint);
+    }
+}
+
+fn push_overflow_hidden(source: &str, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_hint(
+        Some(source),
+        PropertyDeclaration::OverflowX(overflow_x::SpecifiedValue::Hidden));
+    push_hint(
+        Some(source),
+        PropertyDeclaration::OverflowY(overflow_y::SpecifiedValue::Hidden));
+}
@@ -0,0 +1,168 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The presentational hints for the spacing and the layout of tables.
+
+use dom::bindings::inheritance::Castable;
+use dom::bindings::root::LayoutDom;
+use dom::document::LayoutDocumentHelpers;
+use dom::element::Element;
+use dom::htmltablecellelement::{HTMLTableCellElement, HTMLTableCellElementLayoutHelpers};
+use dom::htmltableelement::{HTMLTableElement, HTMLTableElementLayoutHelpers};
+use dom::htmltablerowelement::HTMLTableRowElement;
+use dom::htmltablesectionelement::HTMLTableSectionElement;
+use dom::node::{LayoutNodeHelpers, Node};
+use servo_config::prefs::PREFS;
+use style::attr::LengthOrPercentageOrAuto;
+use style::context::QuirksMode;
+use style::properties::PropertyDeclaration;
+use style::properties::longhands::{border_spacing, table_layout, white_space};
+use style::stylist::PresentationalHintsProfile;
+use style::values::specified;
+use super::{HintContext, HintHandler};
+
+pub const HANDLERS: &'static [HintHandler] = &[
+    HintHandler {
+        local_names: &["table"],
+        attributes: &["cellspacing", "width"],
+        without_attributes: false,
+        synthesize: table,
+    },
+    HintHandler {
+        local_names: &["table"],
+        attributes: &[],
+        without_attributes: true,
+        synthesize: large_table,
+    },
+    HintHandler {
+        local_names: &["td", "th"],
+        attributes: &["nowrap", "width"],
+        without_attributes: true,
+        synthesize: table_cell,
+    },
+];
+
+#[allow(unsafe_code)]
+unsafe fn table(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLTableElement>().unwrap();
+
+    // The hint is emitted regardless of `border-collapse`, since that may
+    // come from author style; layout ignores `border-spacing` for tables
+    // in the collapsing borders model.
+    //
+    // https://html.spec.whatwg.org/multipage/#tables-2:attr-table-cellspacing
+    if let Some(cellspacing) = this.get_cellspacing() {
+        let width_value = specified::Length::from_px(cellspacing as f32);
+        push_hint(
+            Some("cellspacing"),
+            PropertyDeclaration::BorderSpacing(
+                Box::new(border_spacing::SpecifiedValue::new(
+                    width_value.clone().into(),
+                    width_value.into()
+                ))
+            )
+        );
+    }
+
+    // Optionally lay out tables whose width and column widths all come from
+    // attributes with the fixed table layout algorithm, like some legacy
+    // engines did. It is faster, and the page already says how wide every
+    // column should be, but content wider than its column then overflows it
+    // instead of widening the table, so this is off by default.
+    //
+    // The column widths are only sampled when the table is restyled.
+    //
+    // https://drafts.csswg.org/css-tables/#fixed-table-layout
+    let fixed_layout =
+        PREFS.get("layout.tables.attribute_sized_fixed_layout.enabled").as_boolean().unwrap_or(false) &&
+        this.get_width() != LengthOrPercentageOrAuto::Auto &&
+        this.has_explicit_column_widths();
+
+    if fixed_layout {
+        push_hint(
+            Some("width"),
+            PropertyDeclaration::TableLayout(table_layout::SpecifiedValue::Fixed));
+    }
+}
+
+/// Optionally hints that the layout of a large legacy table is
+/// self-contained, since such tables are expensive to lay out. This is only
+/// an optimization hint, so it never changes how the table renders, and
+/// author style overrides it like any other hint.
+///
+/// The cell count is only sampled when the table is restyled.
+///
+/// https://drafts.csswg.org/css-contain/#containment-layout
+#[allow(unsafe_code)]
+unsafe fn large_table(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLTableElement>().unwrap();
+
+    if !PREFS.get("layout.tables.large_table_containment.enabled").as_boolean().unwrap_or(false) {
+        return;
+    }
+    let threshold = PREFS.get("layout.tables.large_table_containment.cell_threshold")
+        .as_u64()
+        .unwrap_or(10000) as usize;
+    if this.get_cell_count() > threshold {
+        push_hint(None, PropertyDeclaration::Contain(specified::Contain::LAYOUT));
+    }
+}
+
+#[allow(unsafe_code)]
+unsafe fn table_cell(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLTableCellElement>().unwrap();
+
+    // The padding of a cell comes from the nearest table whose rows it is in,
+    // so a nested table doesn't inherit the padding of the outer one.
+    //
+    // https://html.spec.whatwg.org/multipage/#tables-2:attr-table-cellpadding
+    if let Some(cellpadding) = table_of_cell(cx.element).and_then(|table| table.get_cellpadding()) {
+        let padding_value = specified::NonNegativeLengthOrPercentage::from(
+            specified::NoCalcLength::Absolute(specified::AbsoluteLength::Px(cellpadding as f32)));
+        push_hint(None, PropertyDeclaration::PaddingTop(padding_value.clone()));
+        push_hint(None, PropertyDeclaration::PaddingRight(padding_value.clone()));
+        push_hint(None, PropertyDeclaration::PaddingBottom(padding_value.clone()));
+        push_hint(None, PropertyDeclaration::PaddingLeft(padding_value));
+    }
+
+    // Gecko drops `nowrap` in quirks mode for a cell that also has a fixed
+    // pixel `width`, so that its content wraps to that width. The spec, like
+    // Blink, keeps `nowrap` and uses the width as the minimum content size of
+    // the cell instead. A percentage width never cancels `nowrap`.
+    //
+    // FIXME: Layout doesn't implement the minimum content size quirk.
+    //
+    // https://html.spec.whatwg.org/multipage/#tables-2:attr-tdth-nowrap
+    // https://quirks.spec.whatwg.org/#the-table-cell-nowrap-minimum-width-calculation-quirk
+    let nowrap = this.get_nowrap() && match this.get_width() {
+        LengthOrPercentageOrAuto::Length(_) => {
+            cx.stylist.presentational_hints_profile() != PresentationalHintsProfile::Gecko ||
+                cx.document.quirks_mode() != QuirksMode::Quirks
+        },
+        _ => true,
+    };
+
+    if nowrap {
+        push_hint(
+            Some("nowrap"),
+            PropertyDeclaration::WhiteSpace(white_space::SpecifiedValue::Nowrap));
+    }
+}
+
+/// The table whose rows contain the table cell `element`, that is, the parent
+/// of its row, or of the section of its row.
+#[allow(unsafe_code)]
+unsafe fn table_of_cell(element: &LayoutDom<Element>) -> Option<LayoutDom<HTMLTableElement>> {
+    let row = match element.upcast::<Node>().parent_node_ref() {
+        Some(parent) if parent.downcast::<HTMLTableRowElement>().is_some() => parent,
+        _ => return None,
+    };
+    let parent = row.parent_node_ref()?;
+    let parent = if parent.downcast::<HTMLTableSectionElement>().is_some() {
+        parent.parent_node_ref()?
+    } else {
+        parent
+    };
+    parent.downcast::<HTMLTableElement>()
+}
@@ -0,0 +1,120 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The presentational hints for the fonts and the position of text.
+
+use dom::bindings::inheritance::Castable;
+use dom::htmlfontelement::{HTMLFontElement, HTMLFontElementLayoutHelpers};
+use servo_atoms::Atom;
+use style::attr::AttrValue;
+use style::properties::PropertyDeclaration;
+use style::properties::longhands::{font_family, font_size};
+use style::str::HTML_SPACE_CHARACTERS;
+use style::values::{computed, specified};
+use super::{HintContext, HintHandler, report_rejected_attribute};
+
+pub const HANDLERS: &'static [HintHandler] = &[
+    HintHandler {
+        local_names: &["font"],
+        attributes: &["face", "size"],
+        without_attributes: false,
+        synthesize: font,
+    },
+    HintHandler {
+        local_names: &["textarea"],
+        attributes: &[],
+        without_attributes: true,
+        synthesize: textarea,
+    },
+    HintHandler {
+        local_names: &["sub"],
+        attributes: &[],
+        without_attributes: true,
+        synthesize: sub,
+    },
+    HintHandler {
+        local_names: &["sup"],
+        attributes: &[],
+        without_attributes: true,
+        synthesize: sup,
+    },
+];
+
+#[allow(unsafe_code)]
+unsafe fn font(cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let this = cx.element.downcast::<HTMLFontElement>().unwrap();
+
+    // Like the value of `font-family`, `face` is a list of families separated
+    // by commas, each of them tried in turn.
+    let font_families = this.get_face().map_or(vec![], |font_family| {
+        font_family.split(',')
+            .map(|family| family.trim_matches(HTML_SPACE_CHARACTERS).trim_matches(|c| c == '"' || c == '\''))
+            .filter(|family| !family.is_empty())
+            .map(|family| computed::font::SingleFontFamily::from_atom(Atom::from(family)))
+            .collect::<Vec<_>>()
+    });
+
+    if !font_families.is_empty() {
+        push_hint(
+            Some("face"),
+            PropertyDeclaration::FontFamily(
+                font_family::SpecifiedValue::Values(
+                    computed::font::FontFamilyList::new(font_families.into_boxed_slice()))));
+    }
+
+    if let Some(font_size) = this.get_size() {
+        push_hint(
+            Some("size"),
+            PropertyDeclaration::FontSize(
+                font_size::SpecifiedValue::from_html_size(font_size as u8)
+            )
+        )
+    } else {
+        // Legacy font sizes that can't be parsed are kept as plain strings.
+        report_rejected_attribute(cx.element, cx.stylist, &local_name!("size"), |value| match *value {
+            AttrValue::String(_) => true,
+            _ => false,
+        });
+    }
+}
+
+/// Text areas use a monospace font by default, like in other engines, which
+/// also makes their `cols` match the width of their text.
+#[allow(unsafe_code)]
+unsafe fn textarea(_cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    let monospace = computed::font::SingleFontFamily::from_atom(atom!("monospace"));
+    push_hint(
+        None,
+        PropertyDeclaration::FontFamily(
+            font_family::SpecifiedValue::Values(
+                computed::font::FontFamilyList::new(vec![monospace].into_boxed_slice()))));
+}
+
+#[allow(unsafe_code)]
+unsafe fn sub(_cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_script_hints(specified::VerticalAlign::Sub, push_hint);
+}
+
+#[allow(unsafe_code)]
+unsafe fn sup(_cx: &HintContext, push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_script_hints(specified::VerticalAlign::Super, push_hint);
+}
+
+/// Subscripts and superscripts are positioned and sized through the
+/// presentational hints too, so that they stay consistent with the other
+/// text-level elements, and author style still overrides them.
+///
+/// https://html.spec.whatwg.org/multipage/#phrasing-content-3
+fn push_script_hints(vertical_align: specified::VerticalAlign,
+                     push_hint: &mut FnMut(Option<&str>, PropertyDeclaration)) {
+    push_hint(
+        None,
+        PropertyDeclaration::VerticalAlign(vertical_align));
+    push_hint(
+        None,
+        PropertyDeclaration::LineHeight(specified::LineHeight::Normal));
+    push_hint(
+        None,
+        PropertyDeclaration::FontSize(specified::FontSize::Smaller));
+}
@@ -143,6 +143,7 @@ pub mod layout_exports {
     pub use dom::element::{Element, LayoutElementHelpers, RawLayoutElementHelpers};
     pub use dom::node::NodeFlags;
     pub use dom::node::{LayoutNodeHelpers, Node};
+    pub use dom::presentational_hints::attribute_affects_presentational_hints;
     pub use dom::text::Text;
 }
 
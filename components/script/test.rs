@@ -15,6 +15,19 @@ pub mod area {
     pub use dom::htmlareaelement::{Area, Shape};
 }
 
+pub mod marquee {
+    pub use dom::marquee::MarqueeDirection;
+}
+
+pub mod presentational_hints {
+    pub use dom::htmlinputelement::InputType;
+    pub use dom::presentational_hints::{attribute_affects_presentational_hints, hint_handlers};
+    pub use dom::presentational_hints::{HintsDigest, character_width, describe_hint, dimension_value};
+    pub use dom::presentational_hints::embedded_content_alignment;
+    pub use dom::presentational_hints::{hints_to_css_string, is_rejected_dimension, line_break_clear};
+    pub use dom::presentational_hints::{serialize_hints, text_field_size};
+}
+
 pub mod sizes {
     pub use dom::htmlimageelement::{parse_a_sizes_attribute, Size};
 }
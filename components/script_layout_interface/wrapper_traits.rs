@@ -265,6 +265,12 @@ pub trait ThreadSafeLayoutNode: Clone + Copy + Debug + GetLayoutData + NodeInfo
 
     fn get_rowspan(&self) -> u32;
 
+    fn get_span(&self) -> u32;
+
+    /// The ordinal value of this node if it is a list item that sets one, like
+    /// an `li` element with a `value` attribute.
+    fn get_ordinal_value(&self) -> Option<i32>;
+
     fn fragment_type(&self) -> FragmentType {
         self.get_pseudo_element_type().fragment_type()
     }
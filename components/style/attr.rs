@@ -7,7 +7,7 @@
 //! [attr]: https://dom.spec.whatwg.org/#interface-attr
 
 use {Atom, LocalName, Namespace, Prefix};
-use app_units::Au;
+use app_units::{Au, AU_PER_PX};
 use cssparser::{self, Color, RGBA};
 use euclid::num::Zero;
 use num_traits::ToPrimitive;
@@ -16,6 +16,7 @@ use selectors::attr::AttrSelectorOperation;
 use servo_arc::Arc;
 use servo_url::ServoUrl;
 use shared_lock::Locked;
+use std::i32;
 use std::str::FromStr;
 use str::{read_exponent, read_fraction, HTML_SPACE_CHARACTERS};
 use str::{read_numbers, split_commas, split_html_space_chars};
@@ -33,6 +34,20 @@ pub enum LengthOrPercentageOrAuto {
     Length(Au),
 }
 
+/// An entry in a [list of dimensions][dims], as used by the `rows` and `cols`
+/// attributes of `<frameset>`.
+///
+/// Percentages are stored as a fraction, like in `LengthOrPercentageOrAuto`.
+///
+/// [dims]: https://html.spec.whatwg.org/multipage/#rules-for-parsing-a-list-of-dimensions
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "servo", derive(MallocSizeOf))]
+pub enum FramesetDimension {
+    Pixels(f64),
+    Percentage(f64),
+    Relative(f64),
+}
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "servo", derive(MallocSizeOf))]
 pub enum AttrValue {
@@ -45,6 +60,7 @@ pub enum AttrValue {
     Length(String, Option<Length>),
     Color(String, Option<RGBA>),
     Dimension(String, LengthOrPercentageOrAuto),
+    DimensionList(String, Vec<FramesetDimension>),
 
     /// Stores a URL, computed from the input string and a document's base URL.
     ///
@@ -70,6 +86,23 @@ pub enum AttrValue {
     ),
 }
 
+/// The largest number of pixels that an `Au` can represent.
+const MAX_AU_PX: i32 = i32::MAX / AU_PER_PX;
+
+/// Converts a number of pixels coming from an attribute to an `Au`, clamping
+/// it to the range `Au` can represent, so that huge values in markup can't
+/// overflow.
+///
+/// See <https://github.com/servo/app_units/issues/22>
+pub fn safe_au_from_px(px: i32) -> Au {
+    Au::from_px(px.max(-MAX_AU_PX).min(MAX_AU_PX))
+}
+
+/// Like `safe_au_from_px`, but for a fractional number of pixels.
+pub fn safe_au_from_f64_px(px: f64) -> Au {
+    Au::from_f64_px(px.max(-MAX_AU_PX as f64).min(MAX_AU_PX as f64))
+}
+
 /// Shared implementation to parse an integer according to
 /// <https://html.spec.whatwg.org/multipage/#rules-for-parsing-integers> or
 /// <https://html.spec.whatwg.org/multipage/#rules-for-parsing-non-negative-integers>
@@ -108,6 +141,29 @@ pub fn parse_unsigned_integer<T: Iterator<Item = char>>(input: T) -> Result<u32,
     do_parse_integer(input).and_then(|result| result.to_u32().ok_or(()))
 }
 
+/// Parse a size used for rendering, like the `size` attribute of an `input`
+/// element.
+///
+/// Unlike the rules for parsing non-negative integers, the whole value must be
+/// an integer, so that for example `10.5` is rejected rather than truncated to
+/// `10`. Zero is rejected too, and larger values than `max`, however large,
+/// are clamped to it.
+pub fn parse_rendering_size(input: &str, max: u32) -> Result<u32, ()> {
+    let digits = input.trim_matches(HTML_SPACE_CHARACTERS);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(());
+    }
+
+    let value = digits.bytes().fold(0u64, |value, digit| {
+        value.saturating_mul(10).saturating_add((digit - b'0') as u64)
+    });
+
+    match value {
+        0 => Err(()),
+        value => Ok(value.min(max as u64) as u32),
+    }
+}
+
 /// Parse a floating-point number according to
 /// <https://html.spec.whatwg.org/multipage/#rules-for-parsing-floating-point-number-values>
 pub fn parse_double(string: &str) -> Result<f64, ()> {
@@ -242,8 +298,14 @@ impl AttrValue {
         AttrValue::Atom(value)
     }
 
+    /// Resolves `url` against `base`, once stripped of leading and trailing
+    /// whitespace. An empty value doesn't resolve to anything, rather than to
+    /// `base` itself, like the legacy `background` attribute requires.
     pub fn from_resolved_url(base: &ServoUrl, url: String) -> AttrValue {
-        let joined = base.join(&url).ok();
+        let joined = match url.trim_matches(HTML_SPACE_CHARACTERS) {
+            "" => None,
+            trimmed => base.join(trimmed).ok(),
+        };
         AttrValue::ResolvedUrl(url, joined)
     }
 
@@ -262,6 +324,11 @@ impl AttrValue {
         AttrValue::Dimension(string, parsed)
     }
 
+    pub fn from_dimension_list(string: String) -> AttrValue {
+        let parsed = parse_frameset_dimensions(&string);
+        AttrValue::DimensionList(string, parsed)
+    }
+
     /// Assumes the `AttrValue` is a `TokenList` and returns its tokens
     ///
     /// ## Panics
@@ -322,6 +389,18 @@ impl AttrValue {
         }
     }
 
+    /// Assumes the `AttrValue` is a `DimensionList` and returns its value
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the `AttrValue` is not a `DimensionList`
+    pub fn as_dimension_list(&self) -> &[FramesetDimension] {
+        match *self {
+            AttrValue::DimensionList(_, ref dimensions) => dimensions,
+            _ => panic!("Dimension list not found"),
+        }
+    }
+
     /// Assumes the `AttrValue` is a `ResolvedUrl` and returns its value.
     ///
     /// ## Panics
@@ -360,7 +439,7 @@ impl AttrValue {
     /// Panics if the `AttrValue` is not a `UInt`
     pub fn as_uint_px_dimension(&self) -> LengthOrPercentageOrAuto {
         if let AttrValue::UInt(_, value) = *self {
-            LengthOrPercentageOrAuto::Length(Au::from_px(value as i32))
+            LengthOrPercentageOrAuto::Length(safe_au_from_px(value.min(i32::MAX as u32) as i32))
         } else {
             panic!("Uint not found");
         }
@@ -388,7 +467,8 @@ impl ::std::ops::Deref for AttrValue {
             AttrValue::Int(ref value, _) |
             AttrValue::ResolvedUrl(ref value, _) |
             AttrValue::Declaration(ref value, _) |
-            AttrValue::Dimension(ref value, _) => &value,
+            AttrValue::Dimension(ref value, _) |
+            AttrValue::DimensionList(ref value, _) => &value,
             AttrValue::Atom(ref value) => &value,
         }
     }
@@ -542,70 +622,137 @@ pub fn parse_legacy_color(mut input: &str) -> Result<RGBA, ()> {
 
 /// Parses a [dimension value][dim]. If unparseable, `Auto` is returned.
 ///
+/// Units are ignored, so "5em" is 5 pixels, and so is anything else that
+/// follows the number, except for a `%` right after it.
+///
 /// [dim]: https://html.spec.whatwg.org/multipage/#rules-for-parsing-dimension-values
 // TODO: this function can be rewritten to return Result<LengthOrPercentage, _>
 pub fn parse_length(mut value: &str) -> LengthOrPercentageOrAuto {
-    // Steps 1 & 2 are not relevant
-
-    // Step 3
+    // Steps 1 to 3.
     value = value.trim_left_matches(HTML_SPACE_CHARACTERS);
 
-    // Step 4
-    if value.is_empty() {
+    // A leading `+` is skipped, as in earlier versions of the spec.
+    if value.starts_with('+') {
+        value = &value[1..]
+    }
+
+    // Step 4.
+    if !value.starts_with(|c: char| c.is_ascii_digit()) {
         return LengthOrPercentageOrAuto::Auto;
     }
 
-    // Step 5
-    if value.starts_with('+') {
-        value = &value[1..]
+    // Step 5.
+    let mut end = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+
+    // Step 7. A full stop that isn't followed by a digit ends the number, but
+    // is skipped over before looking for a `%`.
+    let mut suffix = &value[end..];
+    if suffix.starts_with('.') {
+        let fraction = &suffix[1..];
+        let fraction_len = fraction.find(|c: char| !c.is_ascii_digit()).unwrap_or(fraction.len());
+        if fraction_len > 0 {
+            end += 1 + fraction_len;
+            suffix = &value[end..];
+        } else {
+            suffix = fraction;
+        }
     }
 
-    // Steps 6 & 7
-    match value.chars().nth(0) {
-        Some('0'...'9') => {},
-        _ => return LengthOrPercentageOrAuto::Auto,
+    // The number is made of ASCII digits and at most one full stop followed
+    // by a digit, so it always parses.
+    let number = &value[..end];
+
+    // Steps 6 and 8, that is, the current dimension value.
+    if suffix.starts_with('%') {
+        // Huge percentages overflow to infinity, so clamp them. The bound is
+        // as large as the largest length, which is already more than anything
+        // a percentage can usefully resolve to.
+        let number: f32 = FromStr::from_str(number).unwrap_or(0.);
+        let percentage = (number / 100.0).min(MAX_AU_PX as f32);
+        return LengthOrPercentageOrAuto::Percentage(percentage)
     }
 
-    // Steps 8 to 13
-    // We trim the string length to the minimum of:
-    // 1. the end of the string
-    // 2. the first occurence of a '%' (U+0025 PERCENT SIGN)
-    // 3. the second occurrence of a '.' (U+002E FULL STOP)
-    // 4. the occurrence of a character that is neither a digit nor '%' nor '.'
-    // Note: Step 10 is directly subsumed by FromStr::from_str
-    let mut end_index = value.len();
-    let (mut found_full_stop, mut found_percent) = (false, false);
-    for (i, ch) in value.chars().enumerate() {
-        match ch {
-            '0'...'9' => continue,
-            '%' => {
-                found_percent = true;
-                end_index = i;
-                break;
-            },
-            '.' if !found_full_stop => {
-                found_full_stop = true;
-                continue;
-            },
-            _ => {
-                end_index = i;
+    let number: f64 = FromStr::from_str(number).unwrap_or(0.);
+    LengthOrPercentageOrAuto::Length(safe_au_from_f64_px(number))
+}
+
+/// Parses a [list of dimensions][dims], as used by `<frameset>`.
+///
+/// A malformed list, that is, one without a single number or `*` in it, like
+/// an empty one, is a single relative track, like a missing attribute.
+/// Otherwise, malformed tokens are zero pixels, like the spec says.
+///
+/// [dims]: https://html.spec.whatwg.org/multipage/#rules-for-parsing-a-list-of-dimensions
+pub fn parse_frameset_dimensions(input: &str) -> Vec<FramesetDimension> {
+    // Step 1.
+    let mut raw_input = input;
+
+    // Step 2.
+    if raw_input.ends_with(',') {
+        raw_input = &raw_input[..raw_input.len() - 1];
+    }
+
+    // Steps 3 to 5.
+    let dimensions: Vec<_> = raw_input.split(',').map(parse_frameset_dimension).collect();
+    if dimensions.iter().all(Option::is_none) {
+        return vec![FramesetDimension::Relative(1.)];
+    }
+    dimensions.into_iter().map(|dimension| dimension.unwrap_or(FramesetDimension::Pixels(0.))).collect()
+}
+
+/// Parses a single token of a list of dimensions, following the substeps of
+/// step 5 of <https://html.spec.whatwg.org/multipage/#rules-for-parsing-a-list-of-dimensions>.
+///
+/// Returns `None` for a token with neither a number nor a `*` in it.
+fn parse_frameset_dimension(token: &str) -> Option<FramesetDimension> {
+    let is_space = |c: char| HTML_SPACE_CHARACTERS.contains(&c);
+    let mut input = token.chars().peekable();
+
+    // Substeps 2 and 3.
+    let mut value = 0f64;
+
+    // Substep 4.
+    while input.peek().map_or(false, |&c| is_space(c)) {
+        input.next();
+    }
+
+    // Substep 5.
+    let mut has_digits = false;
+    while let Some(digit) = input.peek().and_then(|c| c.to_digit(10)) {
+        value = value * 10. + digit as f64;
+        has_digits = true;
+        input.next();
+    }
+
+    // Substep 6.
+    if input.peek() == Some(&'.') {
+        input.next();
+        let mut denominator = 1f64;
+        while let Some(&c) = input.peek() {
+            if let Some(digit) = c.to_digit(10) {
+                denominator *= 10.;
+                value += digit as f64 / denominator;
+                has_digits = true;
+            } else if !is_space(c) {
                 break;
-            },
+            }
+            input.next();
         }
     }
-    value = &value[..end_index];
 
-    if found_percent {
-        let result: Result<f32, _> = FromStr::from_str(value);
-        match result {
-            Ok(number) => return LengthOrPercentageOrAuto::Percentage((number as f32) / 100.0),
-            Err(_) => return LengthOrPercentageOrAuto::Auto,
-        }
+    // Substep 7.
+    while input.peek().map_or(false, |&c| is_space(c)) {
+        input.next();
     }
 
-    match FromStr::from_str(value) {
-        Ok(number) => LengthOrPercentageOrAuto::Length(Au::from_f64_px(number)),
-        Err(_) => LengthOrPercentageOrAuto::Auto,
+    // Substeps 8 and 9.
+    match input.peek() {
+        // A lone `*` is treated as `1*`.
+        Some(&'*') if !has_digits => Some(FramesetDimension::Relative(1.)),
+        _ if !has_digits => None,
+        Some(&'%') => Some(FramesetDimension::Percentage(value / 100.)),
+        Some(&'*') => Some(FramesetDimension::Relative(value)),
+        _ => Some(FramesetDimension::Pixels(value)),
     }
 }
 
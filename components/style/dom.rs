@@ -29,7 +29,7 @@ use std::fmt;
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::ops::Deref;
-use stylist::CascadeData;
+use stylist::{CascadeData, Stylist};
 use traversal_flags::TraversalFlags;
 
 /// An opaque handle to a node, which, unlike UnsafeNode, cannot be transformed
@@ -900,6 +900,7 @@ pub trait TElement:
     /// and insert them into `hints`.
     fn synthesize_presentational_hints_for_legacy_attributes<V>(
         &self,
+        stylist: &Stylist,
         visited_handling: VisitedHandlingMode,
         hints: &mut V,
     ) where
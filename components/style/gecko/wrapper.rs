@@ -85,7 +85,7 @@ use std::hash::{Hash, Hasher};
 use std::mem;
 use std::ptr;
 use string_cache::{Atom, Namespace, WeakAtom, WeakNamespace};
-use stylist::CascadeData;
+use stylist::{CascadeData, Stylist};
 
 
 #[inline]
@@ -1701,6 +1701,7 @@ impl<'le> TElement for GeckoElement<'le> {
 
     fn synthesize_presentational_hints_for_legacy_attributes<V>(
         &self,
+        _stylist: &Stylist,
         visited_handling: VisitedHandlingMode,
         hints: &mut V,
     ) where
@@ -519,7 +519,8 @@ ${helpers.single_keyword("page-break-inside",
 // makes the UA rules easier to write.
 ${helpers.single_keyword("resize",
                          "none both horizontal vertical",
-                         products="gecko",
+                         products="gecko servo",
+                         servo_pref="layout.resize.enabled",
                          spec="https://drafts.csswg.org/css-ui/#propdef-resize",
                          flags="APPLIES_TO_PLACEHOLDER",
                          animation_value_type="discrete")}
@@ -592,8 +593,9 @@ ${helpers.predefined_type("contain",
                           "Contain",
                           "specified::Contain::empty()",
                           animation_value_type="discrete",
-                          products="gecko",
+                          products="gecko servo",
                           flags="CREATES_STACKING_CONTEXT FIXPOS_CB",
+                          servo_pref="layout.css.contain.enabled",
                           gecko_pref="layout.css.contain.enabled",
                           spec="https://drafts.csswg.org/css-contain/#contain-property")}
 
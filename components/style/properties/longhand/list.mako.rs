@@ -12,8 +12,7 @@ ${helpers.single_keyword("list-style-position", "outside inside", animation_valu
 
 // TODO(pcwalton): Implement the full set of counter styles per CSS-COUNTER-STYLES [1] 6.1:
 //
-//     decimal-leading-zero, armenian, upper-armenian, lower-armenian, georgian, lower-roman,
-//     upper-roman
+//     decimal-leading-zero, armenian, upper-armenian, lower-armenian, georgian
 //
 // [1]: http://dev.w3.org/csswg/css-counter-styles/
 % if product == "servo":
@@ -22,7 +21,8 @@ ${helpers.single_keyword("list-style-position", "outside inside", animation_valu
         """disc none circle square decimal disclosure-open disclosure-closed lower-alpha upper-alpha
         arabic-indic bengali cambodian cjk-decimal devanagari gujarati gurmukhi kannada khmer lao
         malayalam mongolian myanmar oriya persian telugu thai tibetan cjk-earthly-branch
-        cjk-heavenly-stem lower-greek hiragana hiragana-iroha katakana katakana-iroha""",
+        cjk-heavenly-stem lower-greek hiragana hiragana-iroha katakana katakana-iroha lower-roman
+        upper-roman""",
         animation_value_type="discrete",
         spec="https://drafts.csswg.org/css-lists/#propdef-list-style-type",
         servo_restyle_damage="rebuild_and_reflow",
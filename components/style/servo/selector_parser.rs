@@ -308,6 +308,7 @@ pub enum NonTSPseudoClass {
     ReadWrite,
     ReadOnly,
     ServoNonZeroBorder,
+    ServoDetailsWithoutSummary,
     ServoCaseSensitiveTypeAttr(Atom),
     Target,
     Visited,
@@ -357,6 +358,7 @@ impl ToCss for NonTSPseudoClass {
             ReadWrite => ":read-write",
             ReadOnly => ":read-only",
             ServoNonZeroBorder => ":-servo-nonzero-border",
+            ServoDetailsWithoutSummary => ":-servo-details-without-summary",
             Target => ":target",
             Visited => ":visited",
             Lang(_) | ServoCaseSensitiveTypeAttr(_) => unreachable!(),
@@ -399,6 +401,7 @@ impl NonTSPseudoClass {
             Link |
             Visited |
             ServoNonZeroBorder |
+            ServoDetailsWithoutSummary |
             ServoCaseSensitiveTypeAttr(_) => ElementState::empty(),
         }
     }
@@ -475,6 +478,14 @@ impl<'a, 'i> ::selectors::Parser<'i> for SelectorParser<'a> {
                 }
                 ServoNonZeroBorder
             },
+            "-servo-details-without-summary" => {
+                if !self.in_user_agent_stylesheet() {
+                    return Err(location.new_custom_error(
+                        SelectorParseErrorKind::UnexpectedIdent("-servo-details-without-summary".into())
+                    ))
+                }
+                ServoDetailsWithoutSummary
+            },
             _ => return Err(location.new_custom_error(SelectorParseErrorKind::UnexpectedIdent(name.clone()))),
         };
 
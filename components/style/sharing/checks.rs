@@ -11,6 +11,7 @@ use context::{SelectorFlagsMap, SharedStyleContext};
 use dom::TElement;
 use selectors::NthIndexCache;
 use sharing::{StyleSharingCandidate, StyleSharingTarget};
+use stylist::Stylist;
 
 /// Determines whether a target and a candidate have compatible parents for
 /// sharing.
@@ -74,11 +75,12 @@ where
 pub fn have_same_presentational_hints<E>(
     target: &mut StyleSharingTarget<E>,
     candidate: &mut StyleSharingCandidate<E>,
+    stylist: &Stylist,
 ) -> bool
 where
     E: TElement,
 {
-    target.pres_hints() == candidate.pres_hints()
+    target.pres_hints(stylist) == candidate.pres_hints(stylist)
 }
 
 /// Whether a given element has the same class attribute than a given candidate.
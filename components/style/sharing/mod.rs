@@ -153,13 +153,14 @@ impl ValidationData {
 
     /// Get or compute the list of presentational attributes associated with
     /// this element.
-    pub fn pres_hints<E>(&mut self, element: E) -> &[ApplicableDeclarationBlock]
+    pub fn pres_hints<E>(&mut self, element: E, stylist: &Stylist) -> &[ApplicableDeclarationBlock]
     where
         E: TElement,
     {
         self.pres_hints.get_or_insert_with(|| {
             let mut pres_hints = SmallVec::new();
             element.synthesize_presentational_hints_for_legacy_attributes(
+                stylist,
                 VisitedHandlingMode::AllLinksUnvisited,
                 &mut pres_hints,
             );
@@ -280,8 +281,8 @@ impl<E: TElement> StyleSharingCandidate<E> {
     }
 
     /// Get the pres hints of this candidate.
-    fn pres_hints(&mut self) -> &[ApplicableDeclarationBlock] {
-        self.validation_data.pres_hints(self.element)
+    fn pres_hints(&mut self, stylist: &Stylist) -> &[ApplicableDeclarationBlock] {
+        self.validation_data.pres_hints(self.element, stylist)
     }
 
     /// Get the parent style identity.
@@ -342,8 +343,8 @@ impl<E: TElement> StyleSharingTarget<E> {
     }
 
     /// Get the pres hints of this candidate.
-    fn pres_hints(&mut self) -> &[ApplicableDeclarationBlock] {
-        self.validation_data.pres_hints(self.element)
+    fn pres_hints(&mut self, stylist: &Stylist) -> &[ApplicableDeclarationBlock] {
+        self.validation_data.pres_hints(self.element, stylist)
     }
 
     /// Get the parent style identity.
@@ -786,7 +787,7 @@ impl<E: TElement> StyleSharingCache<E> {
             return None;
         }
 
-        if !checks::have_same_presentational_hints(target, candidate) {
+        if !checks::have_same_presentational_hints(target, candidate, shared.stylist) {
             trace!("Miss: Pres Hints");
             return None;
         }
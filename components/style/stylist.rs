@@ -21,7 +21,7 @@ use malloc_size_of::{MallocShallowSizeOf, MallocSizeOf, MallocSizeOfOps};
 use malloc_size_of::MallocUnconditionalShallowSizeOf;
 use media_queries::Device;
 use properties::{self, CascadeFlags, ComputedValues};
-use properties::{AnimationRules, PropertyDeclarationBlock};
+use properties::{AnimationRules, PropertyDeclaration, PropertyDeclarationBlock};
 use rule_cache::{RuleCache, RuleCacheConditions};
 use rule_tree::{CascadeLevel, RuleTree, ShadowCascadeOrder, StrongRuleNode, StyleSource};
 use selector_map::{PrecomputedHashMap, SelectorMap, SelectorMapEntry};
@@ -287,6 +287,80 @@ pub enum AuthorStylesEnabled {
     No,
 }
 
+/// Which engine to follow when synthesizing the presentational hints of the
+/// few legacy attributes that engines map differently.
+#[derive(Clone, Copy, Debug, Eq, MallocSizeOf, PartialEq)]
+pub enum PresentationalHintsProfile {
+    /// Follow the HTML and quirks specifications.
+    Standard,
+    /// Follow Gecko.
+    Gecko,
+    /// Follow Blink and WebKit.
+    Blink,
+}
+
+impl PresentationalHintsProfile {
+    /// Parses a profile from its name, as used in preferences.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "standard" => PresentationalHintsProfile::Standard,
+            "gecko" => PresentationalHintsProfile::Gecko,
+            "blink" => PresentationalHintsProfile::Blink,
+            _ => return None,
+        })
+    }
+}
+
+/// The element whose presentational hints a `PresentationalHintSynthesizer`
+/// synthesizes.
+pub trait PresentationalHintElement {
+    /// The local name of the element.
+    fn local_name(&self) -> &LocalName;
+
+    /// The namespace of the element.
+    fn namespace(&self) -> &Namespace;
+
+    /// The value of the attribute of the element with the given local name
+    /// and no namespace, if any.
+    fn get_attr(&self, name: &LocalName) -> Option<&str>;
+}
+
+/// Maps attributes that the style system doesn't know about, like those of
+/// custom elements, to presentational hints.
+///
+/// Registered synthesizers run for every element, so they should bail out
+/// early for elements they don't care about.
+pub trait PresentationalHintSynthesizer: Send + Sync {
+    /// Emits the declarations that the attributes of `element` map to,
+    /// together with the name of the attribute each of them comes from.
+    fn synthesize(
+        &self,
+        element: &PresentationalHintElement,
+        emit: &mut FnMut(&LocalName, PropertyDeclaration),
+    );
+
+    /// Whether the `attribute` of an element with the given local name may
+    /// map to hints of this synthesizer, so that changing it needs a restyle.
+    ///
+    /// Every attribute that `synthesize` emits hints from must be reported
+    /// here. The default conservatively reports all of them.
+    fn affects_attribute(&self, _local_name: &LocalName, _attribute: &LocalName) -> bool {
+        true
+    }
+}
+
+/// Receives the values of legacy attributes that would map to presentational
+/// hints but can't be parsed, like `<td width=abc>`, to help authors find out
+/// why they have no effect.
+pub trait AttributeErrorReporter: Send + Sync {
+    /// Reports that the `value` of the `attribute` of an element with the
+    /// given local name is invalid, so it maps to no hint.
+    ///
+    /// A value is reported each time the hints of the element are
+    /// synthesized, so it may be reported more than once.
+    fn report_invalid_attribute(&self, local_name: &LocalName, attribute: &LocalName, value: &str);
+}
+
 /// A wrapper over a DocumentStylesheetSet that can be `Sync`, since it's only
 /// used and exposed via mutable methods in the `Stylist`.
 #[cfg_attr(feature = "servo", derive(MallocSizeOf))]
@@ -360,6 +434,33 @@ pub struct Stylist {
 
     /// The total number of times the stylist has been rebuilt.
     num_rebuilds: usize,
+
+    /// Whether the character widths synthesized from legacy attributes (like
+    /// `<input size>`) use the standard `ch` unit rather than the Servo-specific
+    /// character width.
+    use_ch_unit: bool,
+
+    /// Whether the `left` and `right` values of legacy `align` attributes map
+    /// to the logical `start` and `end` alignments rather than physical ones.
+    logical_alignment_hints: bool,
+
+    /// Which engine to follow for the legacy attributes that engines map to
+    /// different presentational hints.
+    presentational_hints_profile: PresentationalHintsProfile,
+
+    /// Whether the attribute of an element with some local name may map to
+    /// one of the presentational hints that the embedder synthesizes itself.
+    #[cfg_attr(feature = "servo", ignore_malloc_size_of = "function pointers")]
+    built_in_hint_attributes: fn(&LocalName, &LocalName) -> bool,
+
+    /// The synthesizers of presentational hints registered by the embedder,
+    /// in registration order.
+    #[cfg_attr(feature = "servo", ignore_malloc_size_of = "trait objects")]
+    presentational_hint_synthesizers: Vec<Box<PresentationalHintSynthesizer>>,
+
+    /// Where to report invalid values of legacy attributes, if anywhere.
+    #[cfg_attr(feature = "servo", ignore_malloc_size_of = "trait objects")]
+    attribute_error_reporter: Option<Box<AttributeErrorReporter>>,
 }
 
 /// What cascade levels to include when styling elements.
@@ -398,6 +499,12 @@ impl Stylist {
             author_styles_enabled: AuthorStylesEnabled::Yes,
             rule_tree: RuleTree::new(),
             num_rebuilds: 0,
+            use_ch_unit: false,
+            logical_alignment_hints: false,
+            presentational_hints_profile: PresentationalHintsProfile::Standard,
+            built_in_hint_attributes: |_, _| false,
+            presentational_hint_synthesizers: vec![],
+            attribute_error_reporter: None,
         }
     }
 
@@ -1133,6 +1240,115 @@ impl Stylist {
         self.force_stylesheet_origins_dirty(OriginSet::all());
     }
 
+    /// Whether presentational hints should express character widths with the
+    /// `ch` unit.
+    #[inline]
+    pub fn use_ch_unit(&self) -> bool {
+        self.use_ch_unit
+    }
+
+    /// Sets whether presentational hints should express character widths with
+    /// the `ch` unit.
+    ///
+    /// Callers are responsible for restyling the affected elements.
+    pub fn set_use_ch_unit(&mut self, use_ch_unit: bool) {
+        self.use_ch_unit = use_ch_unit;
+    }
+
+    /// Whether presentational hints should map the `left` and `right` values
+    /// of `align` attributes to `start` and `end`, so that they follow the
+    /// direction of the element. This applies to the text alignments, floats
+    /// and margins that all `align` attributes map to.
+    #[inline]
+    pub fn logical_alignment_hints(&self) -> bool {
+        self.logical_alignment_hints
+    }
+
+    /// Sets whether presentational hints should map the `left` and `right`
+    /// values of `align` attributes to `start` and `end`.
+    ///
+    /// Callers are responsible for restyling the affected elements.
+    pub fn set_logical_alignment_hints(&mut self, logical_alignment_hints: bool) {
+        self.logical_alignment_hints = logical_alignment_hints;
+    }
+
+    /// Which engine presentational hints follow for the legacy attributes
+    /// that engines map differently.
+    #[inline]
+    pub fn presentational_hints_profile(&self) -> PresentationalHintsProfile {
+        self.presentational_hints_profile
+    }
+
+    /// Sets which engine presentational hints follow for the legacy
+    /// attributes that engines map differently.
+    ///
+    /// Callers are responsible for restyling the affected elements.
+    pub fn set_presentational_hints_profile(&mut self, profile: PresentationalHintsProfile) {
+        self.presentational_hints_profile = profile;
+    }
+
+    /// Sets what tells whether the attribute of an element with some local
+    /// name may map to one of the built-in presentational hints, which the
+    /// embedder synthesizes itself.
+    pub fn set_built_in_hint_attributes(&mut self, built_in_hint_attributes: fn(&LocalName, &LocalName) -> bool) {
+        self.built_in_hint_attributes = built_in_hint_attributes;
+    }
+
+    /// The synthesizers of presentational hints registered with
+    /// `register_presentational_hint_synthesizer`, in registration order.
+    #[inline]
+    pub fn presentational_hint_synthesizers(&self) -> &[Box<PresentationalHintSynthesizer>] {
+        &self.presentational_hint_synthesizers
+    }
+
+    /// Registers a synthesizer of presentational hints for attributes that
+    /// the style system doesn't map itself.
+    ///
+    /// Registered synthesizers run after the built-in mappings, in the order
+    /// they were registered, so the declarations of the last one win.
+    ///
+    /// This is only supported by Servo, since Gecko maps attributes to
+    /// declarations on its side.
+    ///
+    /// Callers are responsible for restyling the affected elements, both now
+    /// and whenever an attribute that a synthesizer reads changes.
+    pub fn register_presentational_hint_synthesizer(
+        &mut self,
+        synthesizer: Box<PresentationalHintSynthesizer>,
+    ) {
+        self.presentational_hint_synthesizers.push(synthesizer);
+    }
+
+    /// Whether the `attribute` of an element with the given local name may map
+    /// to presentational hints, either built-in or from the registered
+    /// synthesizers, so that changing it needs to restyle the element.
+    pub fn attribute_affects_presentational_hints(
+        &self,
+        local_name: &LocalName,
+        attribute: &LocalName,
+    ) -> bool {
+        (self.built_in_hint_attributes)(local_name, attribute) ||
+            self.presentational_hint_synthesizers
+                .iter()
+                .any(|synthesizer| synthesizer.affects_attribute(local_name, attribute))
+    }
+
+    /// Where invalid values of legacy attributes are reported, if anywhere.
+    #[inline]
+    pub fn attribute_error_reporter(&self) -> Option<&AttributeErrorReporter> {
+        self.attribute_error_reporter.as_ref().map(|reporter| &**reporter)
+    }
+
+    /// Sets where invalid values of legacy attributes are reported. Nothing
+    /// is reported, and nothing is checked, without a reporter.
+    ///
+    /// Values are reported when the hints of an element are synthesized, so
+    /// callers need to restyle the affected elements to get the reports of
+    /// elements that are already styled.
+    pub fn set_attribute_error_reporter(&mut self, reporter: Option<Box<AttributeErrorReporter>>) {
+        self.attribute_error_reporter = reporter;
+    }
+
     /// Returns the applicable CSS declarations for the given element.
     ///
     /// This corresponds to `ElementRuleCollector` in WebKit, and should push to
@@ -1221,6 +1437,7 @@ impl Stylist {
             // https://drafts.csswg.org/css-cascade/#preshint
             let length_before_preshints = applicable_declarations.len();
             element.synthesize_presentational_hints_for_legacy_attributes(
+                self,
                 context.visited_handling(),
                 applicable_declarations,
             );
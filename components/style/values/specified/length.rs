@@ -6,12 +6,12 @@
 //!
 //! [length]: https://drafts.csswg.org/css-values/#lengths
 
-use app_units::Au;
+use app_units::{Au, AU_PER_PX};
 use cssparser::{Parser, Token};
 use euclid::Size2D;
 use font_metrics::FontMetricsQueryResult;
 use parser::{Parse, ParserContext};
-use std::cmp;
+use std::{cmp, i32};
 use std::ops::{Add, Mul};
 use style_traits::{ParseError, SpecifiedValueInfo, StyleParseErrorKind};
 use style_traits::values::specified::AllowedNumericType;
@@ -261,10 +261,15 @@ impl CharacterWidth {
         // in HTML5 § 14.5.4.
         //
         // TODO(pcwalton): Find these from the font.
-        let average_advance = reference_font_size.scale_by(0.5);
-        let max_advance = reference_font_size;
-        let au = average_advance.scale_by(self.0 as CSSFloat - 1.0) + max_advance;
-        au.into()
+        //
+        // This is computed in floating point and clamped, since the number of
+        // characters comes from attributes like `<input size>`, and huge ones
+        // would otherwise overflow the app units.
+        let average_advance = (reference_font_size.0 as f64 * 0.5).round();
+        let max_advance = reference_font_size.0 as f64;
+        let au = (average_advance * (self.0 as f64 - 1.0)).round() + max_advance;
+        let max_au = (i32::MAX / AU_PER_PX * AU_PER_PX) as f64;
+        Au(au.max(-max_au).min(max_au) as i32).into()
     }
 }
 
@@ -14,6 +14,8 @@
 #[cfg(test)] mod headers;
 #[cfg(test)] mod htmlareaelement;
 #[cfg(test)] mod htmlimageelement;
+#[cfg(test)] mod marquee;
+#[cfg(test)] mod presentational_hints;
 
 /**
 ```compile_fail,E0277
@@ -0,0 +1,17 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use script::test::marquee::MarqueeDirection;
+
+#[test]
+fn test_marquee_direction() {
+    assert_eq!(MarqueeDirection::from_attribute(None), MarqueeDirection::Left);
+    assert_eq!(MarqueeDirection::from_attribute(Some("right")), MarqueeDirection::Right);
+    assert_eq!(MarqueeDirection::from_attribute(Some("UP")), MarqueeDirection::Up);
+    assert_eq!(MarqueeDirection::from_attribute(Some("down")), MarqueeDirection::Down);
+    assert_eq!(MarqueeDirection::from_attribute(Some("sideways")), MarqueeDirection::Left);
+    assert_eq!(MarqueeDirection::from_attribute(Some(" up")), MarqueeDirection::Left);
+    assert!(MarqueeDirection::Down.is_vertical());
+    assert!(!MarqueeDirection::Right.is_vertical());
+}
@@ -0,0 +1,270 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use euclid::{TypedScale, TypedSize2D};
+use script::test::presentational_hints::{HintsDigest, InputType, character_width, describe_hint};
+use script::test::presentational_hints::{dimension_value, serialize_hints};
+use script::test::presentational_hints::{hints_to_css_string, is_rejected_dimension, line_break_clear};
+use script::test::presentational_hints::{embedded_content_alignment, text_field_size};
+use script::test::presentational_hints::{attribute_affects_presentational_hints, hint_handlers};
+use style::attr::{AttrValue, parse_nonzero_length};
+use style::context::QuirksMode;
+use style::media_queries::{Device, MediaType};
+use style::LocalName;
+use style::properties::PropertyDeclaration;
+use style::properties::longhands::{clear, float};
+use style::stylist::Stylist;
+use style::values::RGBA;
+use style::values::computed::Percentage;
+use style::values::specified::{AbsoluteLength, CharacterWidth, Color, FontRelativeLength};
+use style::values::specified::{LengthOrPercentageOrAuto, NoCalcLength, VerticalAlign};
+
+fn width(px: f32) -> PropertyDeclaration {
+    PropertyDeclaration::Width(LengthOrPercentageOrAuto::Length(NoCalcLength::Absolute(AbsoluteLength::Px(px))))
+}
+
+fn red() -> PropertyDeclaration {
+    PropertyDeclaration::BackgroundColor(Color::rgba(RGBA::new(255, 0, 0, 255)))
+}
+
+/// The digest of the hints of a `<td width=... bgcolor=red>` cell.
+fn red_cell_digest(width_px: f32) -> u64 {
+    let mut digest = HintsDigest::new();
+    digest.push(&red());
+    digest.push(&width(width_px));
+    digest.finish()
+}
+
+#[test]
+fn test_describe_hint_from_attribute() {
+    assert_eq!(describe_hint("td", Some(("width", "50")), &width(50.)),
+               "td width=\"50\" -> width: 50px");
+}
+
+#[test]
+fn test_describe_hint_without_attribute() {
+    assert_eq!(describe_hint("dd", None, &width(40.)), "dd -> width: 40px");
+}
+
+#[test]
+fn test_hints_digest_equal_for_equal_hints() {
+    assert_eq!(red_cell_digest(100.), red_cell_digest(100.));
+}
+
+#[test]
+fn test_hints_digest_differs_for_different_hints() {
+    assert_ne!(red_cell_digest(100.), red_cell_digest(101.));
+}
+
+#[test]
+fn test_hints_digest_depends_on_order() {
+    let mut a = HintsDigest::new();
+    a.push(&width(100.));
+    a.push(&width(101.));
+    let mut b = HintsDigest::new();
+    b.push(&width(101.));
+    b.push(&width(100.));
+    assert_ne!(a.finish(), b.finish());
+}
+
+#[test]
+fn test_serialize_hints_is_stable() {
+    let declarations = [red(), width(100.)];
+    assert_eq!(serialize_hints(&declarations), serialize_hints(&declarations));
+    assert_eq!(serialize_hints(&declarations), "background-color: rgb(255, 0, 0); width: 100px");
+}
+
+#[test]
+fn test_serialize_hints_is_sorted_by_property() {
+    assert_eq!(serialize_hints(&[red(), width(100.)]), serialize_hints(&[width(100.), red()]));
+}
+
+#[test]
+fn test_serialize_hints_keeps_last_declaration() {
+    assert_eq!(serialize_hints(&[width(50.), red(), width(100.)]), serialize_hints(&[red(), width(100.)]));
+}
+
+#[test]
+fn test_serialize_hints_uses_css_values() {
+    let percentage = PropertyDeclaration::Width(LengthOrPercentageOrAuto::Percentage(Percentage(0.25)));
+    assert_eq!(serialize_hints(&[percentage]), "width: 25%");
+    assert_eq!(serialize_hints(&[]), "");
+}
+
+fn px(px: f32) -> LengthOrPercentageOrAuto {
+    LengthOrPercentageOrAuto::Length(NoCalcLength::Absolute(AbsoluteLength::Px(px)))
+}
+
+/// The `width` hint of a `<td width=...>` cell.
+fn cell_width(value: &str) -> Option<LengthOrPercentageOrAuto> {
+    dimension_value(parse_nonzero_length(value))
+}
+
+#[test]
+fn test_cell_width_pixels() {
+    assert_eq!(cell_width("50"), Some(px(50.)));
+    assert_eq!(cell_width("50px"), Some(px(50.)));
+}
+
+#[test]
+fn test_cell_width_percentage() {
+    assert_eq!(cell_width("25%"), Some(LengthOrPercentageOrAuto::Percentage(Percentage(0.25))));
+}
+
+#[test]
+fn test_cell_width_invalid() {
+    assert_eq!(cell_width(""), None);
+    assert_eq!(cell_width("auto"), None);
+    assert_eq!(cell_width("0"), None);
+    assert_eq!(cell_width("0%"), None);
+}
+
+#[test]
+fn test_text_field_size() {
+    assert_eq!(text_field_size(InputType::Text, 20), Some(20));
+    assert_eq!(text_field_size(InputType::Password, 8), Some(8));
+    assert_eq!(text_field_size(InputType::Search, 1), Some(1));
+    assert_eq!(text_field_size(InputType::Text, 0), None);
+}
+
+#[test]
+fn test_text_field_size_ignored_by_other_controls() {
+    assert_eq!(text_field_size(InputType::Checkbox, 20), None);
+    assert_eq!(text_field_size(InputType::Number, 20), None);
+    assert_eq!(text_field_size(InputType::Hidden, 20), None);
+    assert_eq!(text_field_size(InputType::Submit, 20), None);
+}
+
+#[test]
+fn test_character_width() {
+    let device = Device::new(MediaType::screen(), TypedSize2D::new(0f32, 0f32), TypedScale::new(1.0));
+    let mut stylist = Stylist::new(device, QuirksMode::NoQuirks);
+    assert_eq!(character_width(&stylist, 20), NoCalcLength::ServoCharacterWidth(CharacterWidth(20)));
+    stylist.set_use_ch_unit(true);
+    assert_eq!(character_width(&stylist, 20), NoCalcLength::FontRelative(FontRelativeLength::Ch(20.)));
+}
+
+#[test]
+fn test_attribute_affects_presentational_hints() {
+    let affects = |local_name: &str, attribute: &str| {
+        attribute_affects_presentational_hints(&LocalName::from(local_name), &LocalName::from(attribute))
+    };
+    assert!(affects("td", "width"));
+    assert!(affects("td", "bgcolor"));
+    assert!(affects("input", "size"));
+    assert!(affects("body", "text"));
+    assert!(affects("span", "dir"));
+    assert!(!affects("div", "width"));
+    assert!(!affects("span", "size"));
+    assert!(!affects("td", "id"));
+    assert!(!affects("td", "WIDTH"));
+}
+
+#[test]
+fn test_hints_to_css_string_empty() {
+    assert_eq!(hints_to_css_string(&[]), "");
+}
+
+#[test]
+fn test_hints_to_css_string() {
+    assert_eq!(hints_to_css_string(&[width(50.), red()]), "width: 50px; background-color: rgb(255, 0, 0);");
+    let percentage = PropertyDeclaration::Width(LengthOrPercentageOrAuto::Percentage(Percentage(0.25)));
+    assert_eq!(hints_to_css_string(&[percentage]), "width: 25%;");
+}
+
+#[test]
+fn test_hints_to_css_string_character_width() {
+    let size = PropertyDeclaration::Width(LengthOrPercentageOrAuto::Length(
+        NoCalcLength::ServoCharacterWidth(CharacterWidth(20))));
+    assert_eq!(hints_to_css_string(&[size]), "width: servo-character-width(20);");
+}
+
+#[test]
+fn test_hints_to_css_string_keeps_last_declaration() {
+    assert_eq!(hints_to_css_string(&[width(50.), red(), width(100.)]),
+               "background-color: rgb(255, 0, 0); width: 100px;");
+}
+
+#[test]
+fn test_line_break_clear() {
+    assert_eq!(line_break_clear("left"), Some(clear::SpecifiedValue::Left));
+    assert_eq!(line_break_clear("right"), Some(clear::SpecifiedValue::Right));
+    assert_eq!(line_break_clear("all"), Some(clear::SpecifiedValue::Both));
+    assert_eq!(line_break_clear("both"), Some(clear::SpecifiedValue::Both));
+    assert_eq!(line_break_clear("none"), Some(clear::SpecifiedValue::None));
+}
+
+#[test]
+fn test_line_break_clear_is_case_insensitive() {
+    assert_eq!(line_break_clear("ALL"), Some(clear::SpecifiedValue::Both));
+    assert_eq!(line_break_clear("Left"), Some(clear::SpecifiedValue::Left));
+}
+
+#[test]
+fn test_line_break_clear_invalid() {
+    assert_eq!(line_break_clear("bogus"), None);
+    assert_eq!(line_break_clear(""), None);
+    assert_eq!(line_break_clear(" left"), None);
+}
+
+#[test]
+fn test_is_rejected_dimension() {
+    assert!(is_rejected_dimension(&AttrValue::from_nonzero_dimension("abc".to_owned())));
+    assert!(is_rejected_dimension(&AttrValue::from_dimension("".to_owned())));
+}
+
+#[test]
+fn test_is_rejected_dimension_valid() {
+    assert!(!is_rejected_dimension(&AttrValue::from_nonzero_dimension("50%".to_owned())));
+    assert!(!is_rejected_dimension(&AttrValue::from_dimension("20".to_owned())));
+    assert!(!is_rejected_dimension(&AttrValue::String("abc".to_owned())));
+}
+
+#[test]
+fn test_embedded_content_alignment_floats() {
+    assert_eq!(embedded_content_alignment("right"), Some(PropertyDeclaration::Float(float::SpecifiedValue::Right)));
+    assert_eq!(embedded_content_alignment("LEFT"), Some(PropertyDeclaration::Float(float::SpecifiedValue::Left)));
+}
+
+#[test]
+fn test_embedded_content_alignment_vertical() {
+    assert_eq!(embedded_content_alignment("top"), Some(PropertyDeclaration::VerticalAlign(VerticalAlign::Top)));
+    assert_eq!(embedded_content_alignment("Middle"), Some(PropertyDeclaration::VerticalAlign(VerticalAlign::Middle)));
+    assert_eq!(embedded_content_alignment("bottom"),
+               Some(PropertyDeclaration::VerticalAlign(VerticalAlign::Baseline)));
+}
+
+#[test]
+fn test_embedded_content_alignment_invalid() {
+    assert_eq!(embedded_content_alignment(""), None);
+    assert_eq!(embedded_content_alignment("justify"), None);
+    assert_eq!(embedded_content_alignment(" right"), None);
+}
+
+/// The attributes that the hints of an HTML element with `local_name` come
+/// from, for each of its handlers in turn.
+fn hint_attributes(local_name: &str) -> Vec<&'static str> {
+    hint_handlers(&LocalName::from(local_name)).iter()
+        .flat_map(|handler| handler.attributes().iter().cloned())
+        .collect()
+}
+
+#[test]
+fn test_hint_handlers_table_cell() {
+    let attributes = ["bgcolor", "background", "nowrap", "width", "width", "height", "align", "valign", "dir"];
+    assert_eq!(hint_attributes("td"), attributes);
+    assert_eq!(hint_attributes("th"), attributes);
+}
+
+#[test]
+fn test_hint_handlers_input() {
+    assert_eq!(hint_attributes("input"), ["size", "maxlength", "type", "dir"]);
+    assert_eq!(hint_attributes("textarea"), ["cols", "rows", "disabled", "readonly", "wrap", "dir"]);
+}
+
+#[test]
+fn test_hint_handlers_without_hints() {
+    assert_eq!(hint_attributes("span"), ["dir"]);
+    assert_eq!(hint_attributes("TD"), ["dir"]);
+}
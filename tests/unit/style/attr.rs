@@ -2,9 +2,16 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use app_units::Au;
-use style::attr::{AttrValue, LengthOrPercentageOrAuto, parse_length};
-use style::values::computed::{CalcLengthOrPercentage, Percentage};
+use app_units::{Au, AU_PER_PX};
+use cssparser::RGBA;
+use servo_url::ServoUrl;
+use std::{i32, u32};
+use style::attr::{AttrValue, FramesetDimension, LengthOrPercentageOrAuto};
+use style::attr::{parse_frameset_dimensions, parse_integer, parse_legacy_color, parse_length};
+use style::attr::{parse_rendering_size, parse_unsigned_integer};
+use style::attr::{safe_au_from_f64_px, safe_au_from_px};
+use style::values::computed::{CSSPixelLength, CalcLengthOrPercentage, Percentage};
+use style::values::specified::CharacterWidth;
 
 #[test]
 fn test_length_calc() {
@@ -45,6 +52,36 @@ fn test_parse_double_positive_prefix() {
     }
 }
 
+#[test]
+fn test_parse_integer() {
+    assert_eq!(parse_integer("20".chars()), Ok(20));
+    assert_eq!(parse_integer(" +20px".chars()), Ok(20));
+    assert_eq!(parse_integer("\t\n-7.5".chars()), Ok(-7));
+    assert_eq!(parse_integer("0x10".chars()), Ok(0));
+}
+
+#[test]
+fn test_parse_integer_without_digits() {
+    assert_eq!(parse_integer("".chars()), Err(()));
+    assert_eq!(parse_integer("px".chars()), Err(()));
+    assert_eq!(parse_integer("+".chars()), Err(()));
+    assert_eq!(parse_integer("- 1".chars()), Err(()));
+}
+
+#[test]
+fn test_parse_integer_out_of_range() {
+    assert_eq!(parse_integer("4000000000".chars()), Err(()));
+    assert_eq!(parse_integer("-4000000000".chars()), Err(()));
+}
+
+#[test]
+fn test_parse_unsigned_integer() {
+    assert_eq!(parse_unsigned_integer(" +20px".chars()), Ok(20));
+    assert_eq!(parse_unsigned_integer("4000000000".chars()), Ok(4000000000));
+    assert_eq!(parse_unsigned_integer("-1".chars()), Err(()));
+    assert_eq!(parse_unsigned_integer("abc".chars()), Err(()));
+}
+
 #[test]
 fn test_from_limited_i32_should_be_default_when_less_than_0() {
     let value = String::from("-1");
@@ -86,3 +123,283 @@ pub fn test_parse_length() {
     check("invalid", LengthOrPercentageOrAuto::Auto);
     check("12 followed by invalid", LengthOrPercentageOrAuto::Length(Au::from_px(12)));
 }
+
+#[test]
+fn test_parse_length_ignores_units() {
+    fn check(input: &str, expected: LengthOrPercentageOrAuto) {
+        assert_eq!(parse_length(input), expected, "{}", input);
+    }
+
+    check("5em", LengthOrPercentageOrAuto::Length(Au::from_px(5)));
+    check("5ex", LengthOrPercentageOrAuto::Length(Au::from_px(5)));
+    check("5px", LengthOrPercentageOrAuto::Length(Au::from_px(5)));
+    check("5e2", LengthOrPercentageOrAuto::Length(Au::from_px(5)));
+    check("5.5em", LengthOrPercentageOrAuto::Length(Au::from_f64_px(5.5)));
+}
+
+#[test]
+fn test_parse_length_fractions() {
+    fn check(input: &str, expected: LengthOrPercentageOrAuto) {
+        assert_eq!(parse_length(input), expected, "{}", input);
+    }
+
+    check("5.5", LengthOrPercentageOrAuto::Length(Au::from_f64_px(5.5)));
+    check("5.5%", LengthOrPercentageOrAuto::Percentage(0.055));
+    check("5.5.5", LengthOrPercentageOrAuto::Length(Au::from_f64_px(5.5)));
+    check("5.", LengthOrPercentageOrAuto::Length(Au::from_px(5)));
+    check("5.%", LengthOrPercentageOrAuto::Percentage(0.05));
+    check("5.x%", LengthOrPercentageOrAuto::Length(Au::from_px(5)));
+}
+
+#[test]
+fn test_parse_length_table_cell_width() {
+    // Values of the `width` attribute of `td` elements, as found in legacy
+    // content, and how the rules for parsing dimension values handle them.
+    let cases = [
+        ("100", LengthOrPercentageOrAuto::Length(Au::from_px(100))),
+        (" 100 ", LengthOrPercentageOrAuto::Length(Au::from_px(100))),
+        ("\t\n\x0c\r 100", LengthOrPercentageOrAuto::Length(Au::from_px(100))),
+        ("100px", LengthOrPercentageOrAuto::Length(Au::from_px(100))),
+        ("100.5", LengthOrPercentageOrAuto::Length(Au::from_f64_px(100.5))),
+        ("100.25px", LengthOrPercentageOrAuto::Length(Au::from_f64_px(100.25))),
+        ("12abc", LengthOrPercentageOrAuto::Length(Au::from_px(12))),
+        ("50%", LengthOrPercentageOrAuto::Percentage(0.5)),
+        ("12.5%", LengthOrPercentageOrAuto::Percentage(0.125)),
+        ("50 %", LengthOrPercentageOrAuto::Length(Au::from_px(50))),
+        ("50px%", LengthOrPercentageOrAuto::Length(Au::from_px(50))),
+        ("007", LengthOrPercentageOrAuto::Length(Au::from_px(7))),
+        ("", LengthOrPercentageOrAuto::Auto),
+        ("  ", LengthOrPercentageOrAuto::Auto),
+        ("-5", LengthOrPercentageOrAuto::Auto),
+        ("abc", LengthOrPercentageOrAuto::Auto),
+        ("+", LengthOrPercentageOrAuto::Auto),
+        (".5", LengthOrPercentageOrAuto::Auto),
+        ("%", LengthOrPercentageOrAuto::Auto),
+        ("\u{a0}100", LengthOrPercentageOrAuto::Auto),
+    ];
+
+    for &(input, ref expected) in cases.iter() {
+        assert_eq!(parse_length(input), *expected, "{:?}", input);
+    }
+}
+
+#[test]
+fn test_parse_length_fractional_pixels_round_trip() {
+    for &px in [0.5, 1.25, 100.5, 33.75].iter() {
+        match parse_length(&px.to_string()) {
+            LengthOrPercentageOrAuto::Length(length) => assert_eq!(length.to_f64_px(), px),
+            other => panic!("{} parsed as {:?}", px, other),
+        }
+    }
+}
+
+#[test]
+fn test_parse_frameset_dimensions() {
+    fn check(input: &str, expected: &[FramesetDimension]) {
+        assert_eq!(parse_frameset_dimensions(input), expected);
+    }
+
+    check("100,*,2*", &[FramesetDimension::Pixels(100.),
+                        FramesetDimension::Relative(1.),
+                        FramesetDimension::Relative(2.)]);
+    check("50%,50%", &[FramesetDimension::Percentage(0.5), FramesetDimension::Percentage(0.5)]);
+    check(" 1.5 * , 25 %,", &[FramesetDimension::Relative(1.5), FramesetDimension::Percentage(0.25)]);
+}
+
+#[test]
+fn test_parse_frameset_dimensions_malformed() {
+    fn check(input: &str, expected: &[FramesetDimension]) {
+        assert_eq!(parse_frameset_dimensions(input), expected);
+    }
+
+    check("abc,,12px", &[FramesetDimension::Pixels(0.),
+                         FramesetDimension::Pixels(0.),
+                         FramesetDimension::Pixels(12.)]);
+    check("-5,3.*", &[FramesetDimension::Pixels(0.), FramesetDimension::Relative(3.)]);
+    check("%,*", &[FramesetDimension::Pixels(0.), FramesetDimension::Relative(1.)]);
+}
+
+#[test]
+fn test_parse_frameset_dimensions_fallback() {
+    fn check(input: &str) {
+        assert_eq!(parse_frameset_dimensions(input), &[FramesetDimension::Relative(1.)]);
+    }
+
+    check("");
+    check(",");
+    check(" ");
+    check("abc");
+    check("abc,,x");
+    check("%, -");
+}
+
+#[test]
+fn test_parse_frameset_dimensions_relative() {
+    fn check(input: &str, expected: &[FramesetDimension]) {
+        assert_eq!(parse_frameset_dimensions(input), expected);
+    }
+
+    check("*", &[FramesetDimension::Relative(1.)]);
+    check(" * ", &[FramesetDimension::Relative(1.)]);
+    check("*,*,", &[FramesetDimension::Relative(1.), FramesetDimension::Relative(1.)]);
+    check("0*", &[FramesetDimension::Relative(0.)]);
+    check("25%, * ,100", &[FramesetDimension::Percentage(0.25),
+                           FramesetDimension::Relative(1.),
+                           FramesetDimension::Pixels(100.)]);
+}
+
+#[test]
+fn test_dimension_zero_is_not_auto() {
+    match AttrValue::from_dimension(String::from("0")) {
+        AttrValue::Dimension(_, LengthOrPercentageOrAuto::Length(length)) => assert_eq!(length, Au(0)),
+        _ => panic!("expected a zero length"),
+    }
+
+    match AttrValue::from_dimension(String::new()) {
+        AttrValue::Dimension(_, LengthOrPercentageOrAuto::Auto) => (),
+        _ => panic!("expected auto"),
+    }
+}
+
+#[test]
+fn test_parse_rendering_size() {
+    assert_eq!(parse_rendering_size("10", 10000), Ok(10));
+    assert_eq!(parse_rendering_size(" 10\n", 10000), Ok(10));
+    assert_eq!(parse_rendering_size("10.5", 10000), Err(()));
+    assert_eq!(parse_rendering_size("10px", 10000), Err(()));
+    assert_eq!(parse_rendering_size("-10", 10000), Err(()));
+    assert_eq!(parse_rendering_size("0", 10000), Err(()));
+    assert_eq!(parse_rendering_size("", 10000), Err(()));
+}
+
+#[test]
+fn test_parse_rendering_size_clamps_large_values() {
+    assert_eq!(parse_rendering_size("10001", 10000), Ok(10000));
+    assert_eq!(parse_rendering_size("99999999999", 10000), Ok(10000));
+    assert_eq!(parse_rendering_size("99999999999999999999999999", 10000), Ok(10000));
+}
+
+#[test]
+fn test_safe_au_from_px_clamps() {
+    let max = Au::from_px(i32::MAX / AU_PER_PX);
+    assert_eq!(safe_au_from_px(50), Au::from_px(50));
+    assert_eq!(safe_au_from_px(i32::MAX), max);
+    assert_eq!(safe_au_from_px(i32::MIN), -max);
+    assert_eq!(safe_au_from_f64_px(1e300), max);
+    assert_eq!(safe_au_from_f64_px(-1e300), -max);
+}
+
+#[test]
+fn test_huge_dimensions_do_not_overflow() {
+    let max = LengthOrPercentageOrAuto::Length(Au::from_px(i32::MAX / AU_PER_PX));
+    let huge_values = vec![
+        String::from("2147483647"),
+        String::from("4294967296"),
+        String::from("99999999999999999999"),
+        String::from("2147483647.99"),
+        "9".repeat(400),
+    ];
+
+    // The td and img `width` attributes.
+    for value in huge_values {
+        assert_eq!(parse_length(&value), max, "{}", value);
+        match AttrValue::from_nonzero_dimension(value.clone()) {
+            AttrValue::Dimension(_, length) => assert_eq!(length, max, "{}", value),
+            _ => panic!("expected a dimension"),
+        }
+    }
+
+    // The canvas `width` and `height` attributes.
+    assert_eq!(AttrValue::UInt(String::new(), u32::MAX).as_uint_px_dimension(), max);
+}
+
+#[test]
+fn test_huge_percentages_are_finite() {
+    let max = LengthOrPercentageOrAuto::Percentage((i32::MAX / AU_PER_PX) as f32);
+    assert_eq!(parse_length("999999999999%"), max);
+    assert_eq!(parse_length(&format!("{}%", "9".repeat(400))), max);
+    assert_eq!(parse_length("999999999999.5%abc"), max);
+}
+
+#[test]
+fn test_percentages_with_trailing_garbage() {
+    assert_eq!(parse_length("50%abc"), LengthOrPercentageOrAuto::Percentage(0.5));
+    assert_eq!(parse_length("50%%"), LengthOrPercentageOrAuto::Percentage(0.5));
+    assert_eq!(parse_length("50abc%"), LengthOrPercentageOrAuto::Length(Au::from_px(50)));
+    assert_eq!(parse_length("%50"), LengthOrPercentageOrAuto::Auto);
+}
+
+#[test]
+fn test_parse_length_real_world_garbage() {
+    fn px(value: f64) -> LengthOrPercentageOrAuto {
+        LengthOrPercentageOrAuto::Length(Au::from_f64_px(value))
+    }
+
+    let cases = vec![
+        ("100%%", LengthOrPercentageOrAuto::Percentage(1.)),
+        ("100 px", px(100.)),
+        ("50%px", LengthOrPercentageOrAuto::Percentage(0.5)),
+        ("100 %", px(100.)),
+        ("20.%", LengthOrPercentageOrAuto::Percentage(0.2)),
+        ("20.5%", LengthOrPercentageOrAuto::Percentage(0.205)),
+        ("20.", px(20.)),
+        ("1,000", px(1.)),
+        ("100pt", px(100.)),
+        ("  +30px;", px(30.)),
+        ("\n75%\n", LengthOrPercentageOrAuto::Percentage(0.75)),
+        ("120\"", px(120.)),
+        ("12.5%;", LengthOrPercentageOrAuto::Percentage(0.125)),
+        ("abc", LengthOrPercentageOrAuto::Auto),
+        ("px100", LengthOrPercentageOrAuto::Auto),
+        ("-50", LengthOrPercentageOrAuto::Auto),
+        (".5", LengthOrPercentageOrAuto::Auto),
+        ("%", LengthOrPercentageOrAuto::Auto),
+        ("", LengthOrPercentageOrAuto::Auto),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(parse_length(input), expected, "{:?}", input);
+    }
+}
+
+#[test]
+fn test_parse_legacy_color() {
+    assert_eq!(parse_legacy_color("red"), Ok(RGBA::new(255, 0, 0, 255)));
+    assert_eq!(parse_legacy_color("#f00"), Ok(RGBA::new(255, 0, 0, 255)));
+    assert_eq!(parse_legacy_color("FF0000"), Ok(RGBA::new(255, 0, 0, 255)));
+    assert_eq!(parse_legacy_color("chucknorris"), Ok(RGBA::new(192, 0, 0, 255)));
+    assert_eq!(parse_legacy_color("currentColor"), Ok(RGBA::new(192, 224, 0, 255)));
+}
+
+#[test]
+fn test_parse_legacy_color_ignored_values() {
+    assert_eq!(parse_legacy_color(""), Err(()));
+    assert_eq!(parse_legacy_color("transparent"), Err(()));
+    assert_eq!(parse_legacy_color(" TRANSPARENT "), Err(()));
+}
+
+#[test]
+fn test_from_resolved_url() {
+    let base = ServoUrl::parse("http://example.com/dir/page.html").unwrap();
+    let resolved = |value: &str| {
+        AttrValue::from_resolved_url(&base, value.to_owned()).as_resolved_url().map(|url| url.as_str().to_owned())
+    };
+    assert_eq!(resolved("tile.gif"), Some("http://example.com/dir/tile.gif".to_owned()));
+    assert_eq!(resolved(" \n../tile.gif\t"), Some("http://example.com/tile.gif".to_owned()));
+    assert_eq!(resolved(""), None);
+    assert_eq!(resolved(" \t "), None);
+}
+
+#[test]
+fn test_character_width() {
+    // One maximum advance, plus an average advance of half the font size for
+    // each other character.
+    assert_eq!(CharacterWidth(20).to_computed_value(Au::from_px(16)).px(), 168.);
+}
+
+#[test]
+fn test_huge_character_widths_do_not_overflow() {
+    let max = CSSPixelLength::from(Au::from_px(i32::MAX / AU_PER_PX));
+    assert_eq!(CharacterWidth(i32::MAX).to_computed_value(Au::from_px(16)), max);
+    let width = AttrValue::from_limited_u32("4000000000".to_owned(), 20).as_uint() as i32;
+    assert!(CharacterWidth(width).to_computed_value(Au::from_px(16)).px() > 0.);
+}
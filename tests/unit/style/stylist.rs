@@ -5,6 +5,7 @@
 use cssparser::SourceLocation;
 use euclid::TypedScale;
 use euclid::TypedSize2D;
+use html5ever::{LocalName, Namespace};
 use selectors::parser::{AncestorHashes, Selector};
 use servo_arc::Arc;
 use servo_atoms::Atom;
@@ -12,13 +13,18 @@ use style::context::QuirksMode;
 use style::media_queries::{Device, MediaType};
 use style::properties::{PropertyDeclarationBlock, PropertyDeclaration};
 use style::properties::{longhands, Importance};
+use style::rule_tree::CascadeLevel;
 use style::selector_map::SelectorMap;
 use style::selector_parser::{SelectorImpl, SelectorParser};
 use style::shared_lock::SharedRwLock;
 use style::stylesheets::StyleRule;
-use style::stylist::{Stylist, Rule};
+use std::sync::Mutex;
+use style::stylist::{AttributeErrorReporter, PresentationalHintElement, PresentationalHintSynthesizer};
+use style::stylist::{Rule, Stylist};
 use style::stylist::needs_revalidation_for_testing;
 use style::thread_state::{self, ThreadState};
+use style::values::computed::Percentage;
+use style::values::specified::LengthOrPercentageOrAuto;
 
 /// Helper method to get some Rules from selector strings.
 /// Each sublist of the result contains the Rules for one StyleRule.
@@ -178,6 +184,19 @@ fn test_insert() {
     assert!(selector_map.class_hash.get(&Atom::from("intro"), QuirksMode::NoQuirks).is_none());
 }
 
+#[test]
+fn test_presentational_hints_cascade_level() {
+    // https://drafts.csswg.org/css-cascade/#preshint
+    assert!(!CascadeLevel::PresHints.is_important());
+    assert!(CascadeLevel::UANormal < CascadeLevel::PresHints);
+    assert!(CascadeLevel::UserNormal < CascadeLevel::PresHints);
+    assert!(CascadeLevel::PresHints < CascadeLevel::InnerShadowNormal);
+    assert!(CascadeLevel::PresHints < CascadeLevel::SameTreeAuthorNormal);
+    assert!(CascadeLevel::PresHints < CascadeLevel::StyleAttributeNormal);
+    assert!(CascadeLevel::PresHints < CascadeLevel::SameTreeAuthorImportant);
+    assert!(CascadeLevel::PresHints < CascadeLevel::UAImportant);
+}
+
 fn mock_stylist() -> Stylist {
     let device = Device::new(MediaType::screen(), TypedSize2D::new(0f32, 0f32), TypedScale::new(1.0));
     Stylist::new(device, QuirksMode::NoQuirks)
@@ -199,3 +218,174 @@ fn test_stylist_rule_tree_accessors() {
     stylist.rule_tree();
     stylist.rule_tree().root();
 }
+
+#[test]
+fn test_stylist_use_ch_unit() {
+    thread_state::initialize(ThreadState::LAYOUT);
+    let mut stylist = mock_stylist();
+    assert!(!stylist.use_ch_unit());
+    stylist.set_use_ch_unit(true);
+    assert!(stylist.use_ch_unit());
+}
+
+struct MockHintElement {
+    local_name: LocalName,
+    namespace: Namespace,
+    attributes: Vec<(LocalName, String)>,
+}
+
+impl PresentationalHintElement for MockHintElement {
+    fn local_name(&self) -> &LocalName {
+        &self.local_name
+    }
+
+    fn namespace(&self) -> &Namespace {
+        &self.namespace
+    }
+
+    fn get_attr(&self, name: &LocalName) -> Option<&str> {
+        self.attributes.iter().find(|&&(ref attr, _)| attr == name).map(|&(_, ref value)| &**value)
+    }
+}
+
+/// Maps the `data-width` attribute of `x-gauge` elements to a percentage
+/// width.
+struct GaugeSynthesizer;
+
+impl PresentationalHintSynthesizer for GaugeSynthesizer {
+    fn synthesize(&self,
+                  element: &PresentationalHintElement,
+                  emit: &mut FnMut(&LocalName, PropertyDeclaration)) {
+        if element.namespace() != &ns!(html) || element.local_name() != &LocalName::from("x-gauge") {
+            return;
+        }
+        let attribute = LocalName::from("data-width");
+        if let Some(width) = element.get_attr(&attribute).and_then(|value| value.parse::<f32>().ok()) {
+            emit(&attribute, PropertyDeclaration::Width(
+                LengthOrPercentageOrAuto::Percentage(Percentage(width / 100.))));
+        }
+    }
+
+    fn affects_attribute(&self, local_name: &LocalName, attribute: &LocalName) -> bool {
+        local_name == &LocalName::from("x-gauge") && attribute == &LocalName::from("data-width")
+    }
+}
+
+/// Makes every element auto-sized.
+struct AutoWidthSynthesizer;
+
+impl PresentationalHintSynthesizer for AutoWidthSynthesizer {
+    fn synthesize(&self,
+                  _: &PresentationalHintElement,
+                  emit: &mut FnMut(&LocalName, PropertyDeclaration)) {
+        emit(&LocalName::from("data-width"), PropertyDeclaration::Width(LengthOrPercentageOrAuto::Auto));
+    }
+}
+
+fn synthesize_registered_hints(stylist: &Stylist, element: &MockHintElement) -> Vec<(LocalName, PropertyDeclaration)> {
+    let mut hints = vec![];
+    for synthesizer in stylist.presentational_hint_synthesizers() {
+        synthesizer.synthesize(element, &mut |attribute, declaration| {
+            hints.push((attribute.clone(), declaration))
+        });
+    }
+    hints
+}
+
+#[test]
+fn test_stylist_presentational_hint_synthesizers() {
+    thread_state::initialize(ThreadState::LAYOUT);
+    let mut stylist = mock_stylist();
+    let gauge = MockHintElement {
+        local_name: LocalName::from("x-gauge"),
+        namespace: ns!(html),
+        attributes: vec![(LocalName::from("data-width"), "70".to_owned())],
+    };
+    let div = MockHintElement {
+        local_name: local_name!("div"),
+        namespace: ns!(html),
+        attributes: vec![(LocalName::from("data-width"), "70".to_owned())],
+    };
+    assert!(synthesize_registered_hints(&stylist, &gauge).is_empty());
+
+    stylist.register_presentational_hint_synthesizer(Box::new(GaugeSynthesizer));
+    let width = PropertyDeclaration::Width(LengthOrPercentageOrAuto::Percentage(Percentage(0.7)));
+    assert_eq!(synthesize_registered_hints(&stylist, &gauge),
+               vec![(LocalName::from("data-width"), width.clone())]);
+    assert!(synthesize_registered_hints(&stylist, &div).is_empty());
+
+    // Synthesizers run in registration order, so the last one wins.
+    stylist.register_presentational_hint_synthesizer(Box::new(AutoWidthSynthesizer));
+    assert_eq!(synthesize_registered_hints(&stylist, &gauge),
+               vec![(LocalName::from("data-width"), width),
+                    (LocalName::from("data-width"), PropertyDeclaration::Width(LengthOrPercentageOrAuto::Auto))]);
+}
+
+fn table_cell_width(local_name: &LocalName, attribute: &LocalName) -> bool {
+    *local_name == local_name!("td") && *attribute == local_name!("width")
+}
+
+#[test]
+fn test_stylist_attribute_affects_presentational_hints() {
+    thread_state::initialize(ThreadState::LAYOUT);
+    let mut stylist = mock_stylist();
+    assert!(!stylist.attribute_affects_presentational_hints(&local_name!("td"), &local_name!("width")));
+    stylist.set_built_in_hint_attributes(table_cell_width);
+    assert!(stylist.attribute_affects_presentational_hints(&local_name!("td"), &local_name!("width")));
+    assert!(!stylist.attribute_affects_presentational_hints(&local_name!("td"), &local_name!("id")));
+    assert!(!stylist.attribute_affects_presentational_hints(&local_name!("div"), &local_name!("width")));
+
+    let gauge = LocalName::from("x-gauge");
+    let data_width = LocalName::from("data-width");
+    assert!(!stylist.attribute_affects_presentational_hints(&gauge, &data_width));
+    stylist.register_presentational_hint_synthesizer(Box::new(GaugeSynthesizer));
+    assert!(stylist.attribute_affects_presentational_hints(&gauge, &data_width));
+    assert!(!stylist.attribute_affects_presentational_hints(&local_name!("div"), &data_width));
+
+    // Synthesizers that don't say which attributes they read are assumed to
+    // read all of them.
+    stylist.register_presentational_hint_synthesizer(Box::new(AutoWidthSynthesizer));
+    assert!(stylist.attribute_affects_presentational_hints(&local_name!("div"), &data_width));
+}
+
+#[test]
+fn test_stylist_reports_every_attribute_synthesizers_read() {
+    thread_state::initialize(ThreadState::LAYOUT);
+    let mut stylist = mock_stylist();
+    stylist.register_presentational_hint_synthesizer(Box::new(GaugeSynthesizer));
+    let gauge = MockHintElement {
+        local_name: LocalName::from("x-gauge"),
+        namespace: ns!(html),
+        attributes: vec![(LocalName::from("data-width"), "70".to_owned())],
+    };
+    let hints = synthesize_registered_hints(&stylist, &gauge);
+    assert!(!hints.is_empty());
+    for (attribute, _) in hints {
+        assert!(stylist.attribute_affects_presentational_hints(&gauge.local_name, &attribute), "{}", attribute);
+    }
+}
+
+struct RecordingReporter(Arc<Mutex<Vec<(LocalName, LocalName, String)>>>);
+
+impl AttributeErrorReporter for RecordingReporter {
+    fn report_invalid_attribute(&self, local_name: &LocalName, attribute: &LocalName, value: &str) {
+        self.0.lock().unwrap().push((local_name.clone(), attribute.clone(), value.to_owned()));
+    }
+}
+
+#[test]
+fn test_stylist_attribute_error_reporter() {
+    thread_state::initialize(ThreadState::LAYOUT);
+    let mut stylist = mock_stylist();
+    assert!(stylist.attribute_error_reporter().is_none());
+
+    let reports = Arc::new(Mutex::new(vec![]));
+    stylist.set_attribute_error_reporter(Some(Box::new(RecordingReporter(reports.clone()))));
+    stylist.attribute_error_reporter().unwrap()
+        .report_invalid_attribute(&local_name!("td"), &local_name!("width"), "abc");
+    assert_eq!(*reports.lock().unwrap(),
+               vec![(local_name!("td"), local_name!("width"), "abc".to_owned())]);
+
+    stylist.set_attribute_error_reporter(None);
+    assert!(stylist.attribute_error_reporter().is_none());
+}